@@ -5,7 +5,7 @@ use unicode_width::UnicodeWidthChar;
 ///
 /// ```
 /// // "\u{1b}[1;31mHello World\u{1b}[0m"
-/// ansi_term::Color::Red.bold().paint("Hello")
+/// ansi_term::Color::Red.bold().paint("Hello");
 /// ```
 ///
 /// Truncating the above to a length of 5 would result in:
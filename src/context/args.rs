@@ -87,6 +87,15 @@ pub trait Reconciler: CommandFactory + FromArgMatches {
                     };
                 },
                 ArgAction::SetFalse => continue,
+                ArgAction::Append => {
+                    let Ok(Some(raw)) = argument_source.try_get_raw(id_str) else {
+                        continue;
+                    };
+                    for value in raw {
+                        final_args.push(key.clone());
+                        final_args.push(value.to_os_string());
+                    }
+                },
                 _ => {
                     let Ok(Some(raw)) = argument_source.try_get_raw(id_str) else {
                         continue;
@@ -2,12 +2,77 @@ use clap::ValueEnum;
 use once_cell::sync::OnceCell;
 use std::{env, ffi::OsString};
 
-pub static NO_COLOR: OnceCell<Option<OsString>> = OnceCell::new();
+/// Color related environment variables read in at startup.
+pub static COLOR_ENV: OnceCell<Env> = OnceCell::new();
 
-/// Reads in the `NO_COLOR` environment variable to determine whether or not to display color in
-/// the output.
-pub fn no_color_env() {
-    let _ = NO_COLOR.set(env::var_os("NO_COLOR"));
+/// Snapshot of the environment variables that have a say in whether or not output is colorized.
+#[derive(Debug, Default)]
+pub struct Env {
+    /// <https://no-color.org/>
+    pub no_color: Option<OsString>,
+
+    /// <https://bixense.com/clicolors/>
+    pub clicolor: Option<OsString>,
+
+    /// <https://bixense.com/clicolors/>
+    pub clicolor_force: Option<OsString>,
+
+    /// Advertises whether the terminal supports 24-bit color.
+    pub colorterm: Option<OsString>,
+}
+
+/// Reads in the `NO_COLOR`, `CLICOLOR`, and `CLICOLOR_FORCE` environment variables to determine
+/// whether or not to display color in the output as well as `COLORTERM` to determine whether or
+/// not 24-bit color is supported.
+pub fn read_env() {
+    let _ = COLOR_ENV.set(Env {
+        no_color: env::var_os("NO_COLOR"),
+        clicolor: env::var_os("CLICOLOR"),
+        clicolor_force: env::var_os("CLICOLOR_FORCE"),
+        colorterm: env::var_os("COLORTERM"),
+    });
+}
+
+impl Env {
+    /// Determines whether or not color should be disabled. Precedence from highest to lowest:
+    ///
+    /// 1. `--color none` disables color and `--color force` enables it
+    /// 2. `NO_COLOR` set to a non-empty value disables color
+    /// 3. `CLICOLOR_FORCE` set to a value other than `0` enables color
+    /// 4. `CLICOLOR=0` disables color
+    /// 5. Color is enabled if stdout is a tty
+    pub fn no_color(&self, coloring: Coloring, stdout_is_tty: bool) -> bool {
+        match coloring {
+            Coloring::None => return true,
+            Coloring::Force => return false,
+            Coloring::Auto => (),
+        }
+
+        if self.no_color.as_ref().is_some_and(|var| !var.is_empty()) {
+            return true;
+        }
+
+        if self
+            .clicolor_force
+            .as_ref()
+            .is_some_and(|var| !var.is_empty() && var != "0")
+        {
+            return false;
+        }
+
+        if self.clicolor.as_ref().is_some_and(|var| var == "0") {
+            return true;
+        }
+
+        !stdout_is_tty
+    }
+
+    /// Whether or not the terminal supports 24-bit color as advertised by `COLORTERM`.
+    pub fn truecolor(&self) -> bool {
+        self.colorterm
+            .as_ref()
+            .is_some_and(|var| var == "truecolor" || var == "24bit")
+    }
 }
 
 /// Enum to determine how the output should be colorized.
@@ -23,3 +88,54 @@ pub enum Coloring {
     /// Turn on colorization always
     Force,
 }
+
+#[test]
+fn test_color_env_precedence() {
+    let env = |no_color: Option<&str>, clicolor: Option<&str>, clicolor_force: Option<&str>| Env {
+        no_color: no_color.map(OsString::from),
+        clicolor: clicolor.map(OsString::from),
+        clicolor_force: clicolor_force.map(OsString::from),
+        colorterm: None,
+    };
+
+    let unset = env(None, None, None);
+    assert!(!unset.no_color(Coloring::Auto, true));
+    assert!(unset.no_color(Coloring::Auto, false));
+    assert!(unset.no_color(Coloring::None, true));
+    assert!(!unset.no_color(Coloring::Force, false));
+
+    let no_color = env(Some("1"), None, Some("1"));
+    assert!(no_color.no_color(Coloring::Auto, true));
+    assert!(!no_color.no_color(Coloring::Force, false));
+
+    let empty_no_color = env(Some(""), None, None);
+    assert!(!empty_no_color.no_color(Coloring::Auto, true));
+
+    let force = env(None, Some("0"), Some("1"));
+    assert!(!force.no_color(Coloring::Auto, false));
+    assert!(force.no_color(Coloring::None, true));
+
+    let force_zero = env(None, None, Some("0"));
+    assert!(force_zero.no_color(Coloring::Auto, false));
+
+    let clicolor_off = env(None, Some("0"), None);
+    assert!(clicolor_off.no_color(Coloring::Auto, true));
+    assert!(!clicolor_off.no_color(Coloring::Force, false));
+
+    let clicolor_on = env(None, Some("1"), None);
+    assert!(!clicolor_on.no_color(Coloring::Auto, true));
+    assert!(clicolor_on.no_color(Coloring::Auto, false));
+}
+
+#[test]
+fn test_truecolor() {
+    let env = |colorterm: Option<&str>| Env {
+        colorterm: colorterm.map(OsString::from),
+        ..Env::default()
+    };
+
+    assert!(env(Some("truecolor")).truecolor());
+    assert!(env(Some("24bit")).truecolor());
+    assert!(!env(Some("8bit")).truecolor());
+    assert!(!env(None).truecolor());
+}
@@ -21,6 +21,9 @@ pub struct Properties {
 
     #[cfg(unix)]
     pub max_group_width: usize,
+
+    #[cfg(unix)]
+    pub max_xattr_width: usize,
 }
 
 impl From<&Context> for Properties {
@@ -44,6 +47,8 @@ impl From<&Context> for Properties {
             max_owner_width: 0,
             #[cfg(unix)]
             max_group_width: 0,
+            #[cfg(unix)]
+            max_xattr_width: 0,
         }
     }
 }
@@ -16,4 +16,13 @@ pub enum Error {
 
     #[error("'#{0}' has a type that is invalid")]
     InvalidArgument(String),
+
+    #[error("'{0}' is not a valid key of the [theme] table")]
+    InvalidThemeKey(String),
+
+    #[error("'{1}' of extension '{0}' in the [icons] table is not a valid Unicode codepoint")]
+    InvalidCodepoint(String, String),
+
+    #[error("'{1}' of extension '{0}' in the [icons] table is not a valid 8-bit color code")]
+    InvalidColorCode(String, String),
 }
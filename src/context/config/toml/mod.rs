@@ -1,6 +1,7 @@
+use crate::{icons::Override, styles::Glyphs};
 use config::{Config, File, Value, ValueKind};
 use error::Error;
-use std::{env, ffi::OsString};
+use std::{collections::HashMap, env, ffi::OsString, path::PathBuf};
 
 /// Errors associated with loading and parsing the toml config file.
 pub mod error;
@@ -8,6 +9,12 @@ pub mod error;
 /// Testing related to `.erdtree.toml`.
 pub mod test;
 
+/// Name of the table in `.erdtree.toml` used to customize the glyphs that make up tree branches.
+const THEME_TABLE: &str = "theme";
+
+/// Name of the table in `.erdtree.toml` used to override the icons of file extensions.
+const ICONS_TABLE: &str = "icons";
+
 /// Represents an instruction on how to handle a single key-value pair, which makes up a single
 /// command-line argument, when constructing the arguments vector.
 enum ArgInstructions {
@@ -38,6 +45,11 @@ pub fn parse(config: Config, named_table: Option<&str>) -> Result<Vec<OsString>,
 
         args_map = new_conf;
     } else {
+        // TOML doesn't allow `icons = true` alongside the `[icons]` table so the latter implies it.
+        if matches!(args_map.get(ICONS_TABLE), Some(v) if matches!(v.kind, ValueKind::Table(_))) {
+            args_map.insert(ICONS_TABLE.to_owned(), Value::from(true));
+        }
+
         args_map.retain(|_k, v| !matches!(v.kind, ValueKind::Table(_)));
     }
 
@@ -65,25 +77,143 @@ pub fn parse(config: Config, named_table: Option<&str>) -> Result<Vec<OsString>,
     Ok(parsed_args)
 }
 
-/// Reads in `.erdtree.toml` file.
+/// Reads the `[theme]` table of a `Config` generated from [`load`] into [`Glyphs`], falling back
+/// to the default glyph for any key that isn't set. Returns `None` if there is no `[theme]` table.
+pub fn parse_theme(config: &Config) -> Result<Option<Glyphs>, Error> {
+    let Ok(table) = config.get_table(THEME_TABLE) else {
+        return Ok(None);
+    };
+
+    let mut glyphs = Glyphs::default();
+
+    for (key, value) in table {
+        let ValueKind::String(glyph) = value.kind else {
+            return Err(Error::InvalidArgument(key));
+        };
+
+        match key.as_str() {
+            "vertical" => glyphs.vertical = glyph,
+            "branch" => glyphs.branch = glyph,
+            "last_branch" => glyphs.last_branch = glyph,
+            "top_branch" => glyphs.top_branch = glyph,
+            "indent" => glyphs.indent = glyph,
+            _ => return Err(Error::InvalidThemeKey(key)),
+        }
+    }
+
+    Ok(Some(glyphs))
+}
+
+/// Reads the `[icons]` table of a `Config` generated from [`load`] into a map of file extensions
+/// to user-defined icons. Each extension maps either to a codepoint or to a table with a
+/// `codepoint` key and an optional 8-bit `color` key:
+///
+/// ```toml
+/// [icons]
+/// rs = "e7a8"
+/// txt = { codepoint = "U+F0219", color = 113 }
+/// ```
+///
+/// Returns `None` if there is no `[icons]` table.
+pub fn parse_icons(config: &Config) -> Result<Option<HashMap<String, Override>>, Error> {
+    let Ok(table) = config.get_table(ICONS_TABLE) else {
+        return Ok(None);
+    };
+
+    let mut overrides = HashMap::with_capacity(table.len());
+
+    for (ext, value) in table {
+        let (codepoint, color) = match value.kind {
+            ValueKind::String(codepoint) => (codepoint, None),
+            ValueKind::Table(mut attrs) => {
+                let codepoint = match attrs.remove("codepoint").map(|v| v.kind) {
+                    Some(ValueKind::String(codepoint)) => codepoint,
+                    _ => return Err(Error::InvalidArgument(format!("{ICONS_TABLE}.{ext}"))),
+                };
+                (codepoint, attrs.remove("color"))
+            },
+            _ => return Err(Error::InvalidArgument(format!("{ICONS_TABLE}.{ext}"))),
+        };
+
+        let icon = parse_codepoint(&codepoint)
+            .ok_or_else(|| Error::InvalidCodepoint(ext.clone(), codepoint))?;
+
+        let color = color
+            .map(|value| {
+                value
+                    .clone()
+                    .into_int()
+                    .ok()
+                    .and_then(|code| u8::try_from(code).ok())
+                    .ok_or_else(|| Error::InvalidColorCode(ext.clone(), value.to_string()))
+            })
+            .transpose()?;
+
+        overrides.insert(ext, Override { icon, color });
+    }
+
+    Ok(Some(overrides))
+}
+
+/// Parses a hexadecimal Unicode codepoint optionally prefixed with `U+` or `0x`, e.g. `U+F0219`.
+fn parse_codepoint(codepoint: &str) -> Option<char> {
+    let hex = codepoint
+        .strip_prefix("U+")
+        .or_else(|| codepoint.strip_prefix("u+"))
+        .or_else(|| codepoint.strip_prefix("0x"))
+        .unwrap_or(codepoint);
+
+    u32::from_str_radix(hex, 16).ok().and_then(char::from_u32)
+}
+
+/// Reads in and merges every `.erdtree.toml` that can be found. Files are layered from most
+/// global to most local such that more local files override arguments of more global ones:
+///
+/// 1. The config in `$HOME` (Unix) or `%APPDATA%` (Windows)
+/// 2. The config in `$XDG_CONFIG_HOME` (Unix)
+/// 3. `$ERDTREE_TOML_PATH`
+///
+/// `.erdtree.toml` in the current working directory is deliberately not loaded as it would let
+/// any directory that `erd` happens to be invoked from configure it.
 pub fn load() -> Result<Config, Error> {
     #[cfg(windows)]
-    return windows::load_toml().ok_or(Error::LoadConfig);
+    let mut paths = windows::config_paths();
 
     #[cfg(unix)]
-    unix::load_toml().ok_or(Error::LoadConfig)
+    let mut paths = unix::config_paths();
+
+    paths.extend(toml_from_env());
+
+    merge(&paths)
 }
 
-/// Attempts to load in `.erdtree.toml` from `$ERDTREE_TOML_PATH`. Will return `None` for whatever
-/// reason.
-fn toml_from_env() -> Option<Config> {
-    let config = env::var_os(super::ERDTREE_TOML_PATH)
-        .map(OsString::into_string)
-        .and_then(Result::ok)?;
+/// Merges the `.erdtree.toml` files at `paths` into a single [`Config`], with files appearing
+/// later taking precedence over files appearing earlier. Paths that don't exist are skipped but an
+/// error is returned if none of them exist.
+pub fn merge(paths: &[PathBuf]) -> Result<Config, Error> {
+    let files = paths
+        .iter()
+        .filter(|path| path.is_file())
+        .filter_map(|path| path.to_str())
+        .filter_map(|path| path.strip_suffix(".toml"))
+        .map(File::with_name)
+        .collect::<Vec<_>>();
+
+    if files.is_empty() {
+        return Err(Error::LoadConfig);
+    }
+
+    let config = files
+        .into_iter()
+        .fold(Config::builder(), |builder, file| builder.add_source(file))
+        .build()?;
 
-    let file = config.strip_suffix(".toml").map(File::with_name)?;
+    Ok(config)
+}
 
-    Config::builder().add_source(file).build().ok()
+/// Path to `.erdtree.toml` as specified by `$ERDTREE_TOML_PATH`.
+fn toml_from_env() -> Option<PathBuf> {
+    env::var_os(super::ERDTREE_TOML_PATH).map(PathBuf::from)
 }
 
 /// Simple utility used to extract the underlying value from the [`Value`] enum that we get when
@@ -122,102 +252,52 @@ fn parse_argument(keyword: &str, arg: &Value) -> Result<ArgInstructions, Error>
     }
 }
 
-/// Concerned with how to load `.erdtree.toml` on Unix systems.
+/// Concerned with where to find `.erdtree.toml` on Unix systems.
 #[cfg(unix)]
 mod unix {
     use super::super::{CONFIG_DIR, ERDTREE_CONFIG_TOML, ERDTREE_DIR, HOME, XDG_CONFIG_HOME};
-    use config::{Config, File};
     use std::{env, path::PathBuf};
 
-    /// Looks for `.erdtree.toml` in the following locations in order:
+    /// Candidate locations of `.erdtree.toml` from most global to most local:
     ///
-    /// - `$ERDTREE_TOML_PATH`
-    /// - `$XDG_CONFIG_HOME/erdtree/.erdtree.toml`
-    /// - `$XDG_CONFIG_HOME/.erdtree.toml`
-    /// - `$HOME/.config/erdtree/.erdtree.toml`
     /// - `$HOME/.erdtree.toml`
-    pub(super) fn load_toml() -> Option<Config> {
-        super::toml_from_env()
-            .or_else(toml_from_xdg_path)
-            .or_else(toml_from_home)
-    }
-
-    /// Looks for `.erdtree.toml` in the following locations in order:
-    ///
-    /// - `$XDG_CONFIG_HOME/erdtree/.erdtree.toml`
+    /// - `$HOME/.config/erdtree/.erdtree.toml`
     /// - `$XDG_CONFIG_HOME/.erdtree.toml`
-    fn toml_from_xdg_path() -> Option<Config> {
-        let config = env::var_os(XDG_CONFIG_HOME).map(PathBuf::from)?;
-
-        let mut file = config
-            .join(ERDTREE_DIR)
-            .join(ERDTREE_CONFIG_TOML)
-            .to_str()
-            .and_then(|s| s.strip_suffix(".toml"))
-            .map(File::with_name);
-
-        if file.is_none() {
-            file = config
-                .join(ERDTREE_CONFIG_TOML)
-                .to_str()
-                .and_then(|s| s.strip_suffix(".toml"))
-                .map(File::with_name);
+    /// - `$XDG_CONFIG_HOME/erdtree/.erdtree.toml`
+    pub(super) fn config_paths() -> Vec<PathBuf> {
+        let mut paths = vec![];
+
+        if let Some(home) = env::var_os(HOME).map(PathBuf::from) {
+            paths.push(home.join(ERDTREE_CONFIG_TOML));
+            paths.push(
+                home.join(CONFIG_DIR)
+                    .join(ERDTREE_DIR)
+                    .join(ERDTREE_CONFIG_TOML),
+            );
         }
 
-        Config::builder().add_source(file?).build().ok()
-    }
-
-    /// Looks for `.erdtree.toml` in the following locations in order:
-    ///
-    /// - `$HOME/.config/erdtree/.erdtree.toml`
-    /// - `$HOME/.erdtree.toml`
-    fn toml_from_home() -> Option<Config> {
-        let home = env::var_os(HOME).map(PathBuf::from)?;
-
-        let mut file = home
-            .join(CONFIG_DIR)
-            .join(ERDTREE_DIR)
-            .join(ERDTREE_CONFIG_TOML)
-            .to_str()
-            .and_then(|s| s.strip_suffix(".toml"))
-            .map(File::with_name);
-
-        if file.is_none() {
-            file = home
-                .join(ERDTREE_CONFIG_TOML)
-                .to_str()
-                .and_then(|s| s.strip_suffix(".toml"))
-                .map(File::with_name);
+        if let Some(config) = env::var_os(XDG_CONFIG_HOME).map(PathBuf::from) {
+            paths.push(config.join(ERDTREE_CONFIG_TOML));
+            paths.push(config.join(ERDTREE_DIR).join(ERDTREE_CONFIG_TOML));
         }
 
-        Config::builder().add_source(file?).build().ok()
+        paths
     }
 }
 
-/// Concerned with how to load `.erdtree.toml` on Windows.
+/// Concerned with where to find `.erdtree.toml` on Windows.
 #[cfg(windows)]
 mod windows {
     use super::super::{ERDTREE_CONFIG_TOML, ERDTREE_DIR};
-    use config::{Config, File};
+    use std::path::PathBuf;
 
-    /// Try to read in config from the following location:
-    /// - `%APPDATA%\erdtree\.erdtree.toml`
-    pub(super) fn load_toml() -> Option<Config> {
-        super::toml_from_env().or_else(toml_from_appdata)
-    }
-
-    /// Try to read in config from the following location:
+    /// Candidate locations of `.erdtree.toml`:
+    ///
     /// - `%APPDATA%\erdtree\.erdtree.toml`
-    fn toml_from_appdata() -> Option<Config> {
-        let app_data = dirs::config_dir()?;
-
-        let file = app_data
-            .join(ERDTREE_DIR)
-            .join(ERDTREE_CONFIG_TOML)
-            .to_str()
-            .and_then(|s| s.strip_suffix(".toml"))
-            .map(File::with_name)?;
-
-        Config::builder().add_source(file).build().ok()
+    pub(super) fn config_paths() -> Vec<PathBuf> {
+        dirs::config_dir()
+            .map(|app_data| app_data.join(ERDTREE_DIR).join(ERDTREE_CONFIG_TOML))
+            .into_iter()
+            .collect()
     }
 }
@@ -89,3 +89,166 @@ fn parse_toml() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn merge_toml() -> Result<(), Box<dyn std::error::Error>> {
+    use std::{ffi::OsString, fs};
+    use tempfile::TempDir;
+
+    let global_dir = TempDir::new()?;
+    let local_dir = TempDir::new()?;
+
+    let global = global_dir.path().join(".erdtree.toml");
+    let local = local_dir.path().join(".erdtree.toml");
+    let missing = local_dir.path().join("missing").join(".erdtree.toml");
+
+    fs::write(
+        &global,
+        r#"
+        icons = true
+        threads = 4
+        "#,
+    )?;
+
+    fs::write(
+        &local,
+        r#"
+        threads = 8
+        "#,
+    )?;
+
+    let config = super::merge(&[global, missing, local])?;
+    let mut toml = super::parse(config, None)?;
+    toml.sort();
+
+    let mut expected = vec![
+        OsString::from("--"),
+        OsString::from("--icons"),
+        OsString::from("--threads"),
+        OsString::from("8"),
+    ];
+    expected.sort();
+
+    assert_eq!(toml, expected, "Local config should override global config");
+
+    assert!(super::merge(&[local_dir.path().join("nope.toml")]).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn parse_theme() -> Result<(), Box<dyn std::error::Error>> {
+    use crate::styles::Glyphs;
+    use std::fs;
+    use tempfile::TempDir;
+
+    let dir = TempDir::new()?;
+    let toml = dir.path().join(".erdtree.toml");
+
+    fs::write(
+        &toml,
+        r#"
+        icons = true
+
+        [theme]
+        vertical = "|  "
+        branch = "+- "
+        "#,
+    )?;
+
+    let config = super::merge(&[toml.clone()])?;
+    let glyphs = super::parse_theme(&config)?.expect("Expected a theme");
+
+    assert_eq!(
+        glyphs,
+        Glyphs {
+            vertical: "|  ".to_owned(),
+            branch: "+- ".to_owned(),
+            ..Glyphs::default()
+        },
+        "Absent keys should fall back to defaults"
+    );
+
+    let args = super::parse(config, None)?;
+    assert!(
+        !args
+            .iter()
+            .any(|arg| arg.to_string_lossy().contains("theme")),
+        "The theme table should not be treated as arguments"
+    );
+
+    fs::write(&toml, "icons = true")?;
+    let config = super::merge(&[toml.clone()])?;
+    assert!(super::parse_theme(&config)?.is_none());
+
+    fs::write(
+        &toml,
+        r#"
+        [theme]
+        trunk = "|"
+        "#,
+    )?;
+    let config = super::merge(&[toml])?;
+    assert!(super::parse_theme(&config).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn parse_icons() -> Result<(), Box<dyn std::error::Error>> {
+    use crate::icons::Override;
+    use std::{ffi::OsString, fs};
+    use tempfile::TempDir;
+
+    let dir = TempDir::new()?;
+    let toml = dir.path().join(".erdtree.toml");
+
+    fs::write(
+        &toml,
+        r#"
+        [icons]
+        rs = "e7a8"
+        txt = { codepoint = "U+F0219", color = 113 }
+        "#,
+    )?;
+
+    let config = super::merge(&[toml.clone()])?;
+    let overrides = super::parse_icons(&config)?.expect("Expected icon overrides");
+
+    assert_eq!(
+        overrides.get("rs"),
+        Some(&Override {
+            icon: '\u{e7a8}',
+            color: None
+        })
+    );
+    assert_eq!(
+        overrides.get("txt"),
+        Some(&Override {
+            icon: '\u{f0219}',
+            color: Some(113)
+        })
+    );
+
+    let args = super::parse(config, None)?;
+    assert_eq!(
+        args,
+        vec![OsString::from("--"), OsString::from("--icons")],
+        "The icons table should only imply '--icons'"
+    );
+
+    for invalid in [r#"rs = "d800""#, r#"rs = "nope""#, r#"rs = { color = 1 }"#] {
+        fs::write(&toml, format!("[icons]\n{invalid}"))?;
+        let config = super::merge(&[toml.clone()])?;
+        assert!(
+            super::parse_icons(&config).is_err(),
+            "Expected '{invalid}' to be rejected"
+        );
+    }
+
+    fs::write(&toml, "[icons]\nrs = { codepoint = \"e7a8\", color = 256 }")?;
+    let config = super::merge(&[toml])?;
+    assert!(super::parse_icons(&config).is_err());
+
+    Ok(())
+}
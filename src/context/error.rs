@@ -21,6 +21,12 @@ pub enum Error {
     #[error("{0}")]
     InvalidRegularExpression(#[from] RegexError),
 
+    #[error("'{0}' is not a valid strftime format string")]
+    InvalidTimeFormat(String),
+
+    #[error("'--scale' must be at most {max} but {0} was provided", max = super::MAX_SCALE)]
+    InvalidScale(usize),
+
     #[error("Missing '--pattern' argument")]
     PatternNotProvided,
 
@@ -0,0 +1,102 @@
+/// Files that are rarely of interest such as operating system metadata and editor swap or backup
+/// files. These are hidden by '--hide-clutter'.
+pub const CLUTTER: [&str; 8] = [
+    ".DS_Store",
+    "Thumbs.db",
+    "desktop.ini",
+    "*~",
+    "*.swp",
+    "*.swo",
+    ".#*",
+    "#*#",
+];
+
+/// Expands shell-style brace alternations such as `*.{rs,toml}` into the individual patterns they
+/// represent, i.e. `*.rs` and `*.toml`. Nested groups are expanded recursively and braces escaped
+/// with a backslash are left untouched so that they may be matched literally. Groups without a
+/// top-level comma aren't alternations and are likewise preserved as-is.
+pub fn expand_braces(pattern: &str) -> Vec<String> {
+    let mut expanded = Vec::new();
+
+    for candidate in expand(pattern) {
+        if !expanded.contains(&candidate) {
+            expanded.push(candidate);
+        }
+    }
+
+    expanded
+}
+
+fn expand(pattern: &str) -> Vec<String> {
+    let Some((open, close, commas)) = find_alternation(pattern) else {
+        return vec![pattern.to_owned()];
+    };
+
+    let prefix = &pattern[..open];
+    let suffix = &pattern[close + 1..];
+
+    let mut bounds = Vec::with_capacity(commas.len() + 2);
+    bounds.push(open);
+    bounds.extend(commas);
+    bounds.push(close);
+
+    bounds
+        .windows(2)
+        .flat_map(|window| {
+            let alternative = &pattern[window[0] + 1..window[1]];
+            expand(&format!("{prefix}{alternative}{suffix}"))
+        })
+        .collect()
+}
+
+/// Finds the first brace group to close that contains a comma belonging to it, returning the byte
+/// offsets of its opening brace, its closing brace, and its commas.
+fn find_alternation(pattern: &str) -> Option<(usize, usize, Vec<usize>)> {
+    let mut groups: Vec<(usize, Vec<usize>)> = Vec::new();
+    let mut escaped = false;
+
+    for (i, ch) in pattern.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+
+        match ch {
+            '\\' => escaped = true,
+            '{' => groups.push((i, Vec::new())),
+            ',' => {
+                if let Some((_, commas)) = groups.last_mut() {
+                    commas.push(i);
+                }
+            },
+            '}' => match groups.pop() {
+                Some((open, commas)) if !commas.is_empty() => return Some((open, i, commas)),
+                _ => (),
+            },
+            _ => (),
+        }
+    }
+
+    None
+}
+
+#[test]
+fn test_expand_braces() {
+    assert_eq!(expand_braces("*.{rs,toml}"), vec!["*.rs", "*.toml"]);
+    assert_eq!(expand_braces("*.txt"), vec!["*.txt"]);
+    assert_eq!(
+        expand_braces("{src,tests}/*.{rs,t{xt,oml}}"),
+        vec![
+            "src/*.rs",
+            "src/*.txt",
+            "src/*.toml",
+            "tests/*.rs",
+            "tests/*.txt",
+            "tests/*.toml"
+        ]
+    );
+    assert_eq!(expand_braces("{a,b{c,d}}"), vec!["a", "bc", "bd"]);
+    assert_eq!(expand_braces(r"\{a,b\}.txt"), vec![r"\{a,b\}.txt"]);
+    assert_eq!(expand_braces("{a}.txt"), vec!["{a}.txt"]);
+    assert_eq!(expand_braces("{,.}log"), vec!["log", ".log"]);
+}
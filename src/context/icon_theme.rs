@@ -0,0 +1,12 @@
+use clap::ValueEnum;
+
+/// Which set of icons to use when '--icons' is enabled.
+#[derive(Clone, Copy, Debug, ValueEnum, PartialEq, Eq, Default)]
+pub enum Type {
+    /// Icons from the Nerd Fonts glyph set; requires a patched font
+    #[default]
+    Nerd,
+
+    /// Emoji which render with most fonts
+    Emoji,
+}
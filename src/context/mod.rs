@@ -1,16 +1,20 @@
-use super::disk_usage::{file_size::DiskUsage, units::PrefixKind};
-use crate::tty;
+use super::disk_usage::{
+    file_size::DiskUsage,
+    units::{self, FixedUnit, PrefixKind, SizeRange},
+};
+use crate::{icons, styles::Glyphs, tty};
 use args::Reconciler;
-use clap::{FromArgMatches, Parser};
+use clap::{parser::ValueSource, FromArgMatches, Parser};
 use color::Coloring;
 use error::Error;
 use ignore::{
+    gitignore::{Gitignore, GitignoreBuilder},
     overrides::{Override, OverrideBuilder},
     DirEntry,
 };
-use regex::Regex;
+use pattern::NameMatcher;
 use std::{
-    borrow::Borrow,
+    collections::HashMap,
     convert::From,
     num::NonZeroUsize,
     path::{Path, PathBuf},
@@ -33,15 +37,24 @@ pub mod dir;
 /// [Context] related errors.
 pub mod error;
 
+/// Preprocessing of glob patterns as well as built-in sets of globs.
+pub mod glob;
+
 /// Common cross-platform file-types.
 pub mod file;
 
+/// Which set of icons to display.
+pub mod icon_theme;
+
 /// For determining the output layout.
 pub mod layout;
 
 /// Utilities to print output.
 pub mod column;
 
+/// Matching of file names against '--pattern'.
+pub mod pattern;
+
 /// Printing order kinds.
 pub mod sort;
 
@@ -49,6 +62,21 @@ pub mod sort;
 #[cfg(unix)]
 pub mod time;
 
+/// When to show the marker for extended attributes in long view.
+#[cfg(unix)]
+pub mod xattr;
+
+/// Tests for [Context].
+#[cfg(test)]
+mod test;
+
+/// Largest number of decimal places accepted by '--scale'.
+pub const MAX_SCALE: usize = 10;
+
+/// Ignore file with gitignore syntax that is read from the root of traversal unless
+/// '--no-ignore' is provided.
+pub const ERDTREEIGNORE: &str = ".erdtreeignore";
+
 /// Defines the CLI.
 #[derive(Parser, Debug)]
 #[command(name = "erdtree")]
@@ -71,22 +99,62 @@ pub struct Context {
     #[arg(short, long, value_enum, default_value_t)]
     pub disk_usage: DiskUsage,
 
+    /// Report lines of code, counting only the lines of files recognized as source files
+    #[arg(long)]
+    pub loc: bool,
+
     /// Follow symlinks
     #[arg(short = 'f', long)]
     pub follow: bool,
 
+    /// Maximum number of symlinks to descend through when following symlinks; 0 shows symlinked
+    /// directories without descending into them
+    #[arg(long, value_name = "NUM", requires = "follow")]
+    pub follow_depth: Option<usize>,
+
+    /// Hide symlinks whose targets don't exist
+    #[arg(long)]
+    pub no_broken: bool,
+
+    /// List the entries of zip archives along with their uncompressed sizes as if the archives
+    /// were directories
+    #[cfg(feature = "zip")]
+    #[arg(long)]
+    pub into_archives: bool,
+
     /// Print disk usage in human-readable format
     #[arg(short = 'H', long)]
     pub human: bool,
 
+    /// Report all human-readable disk usages in the unit most suitable for the largest one
+    #[arg(long, requires = "human")]
+    pub auto_unit_column: bool,
+
+    /// Pin the width of the disk usage column, padding or rounding sizes as needed
+    #[arg(long, value_name = "N")]
+    pub fixed_size_width: Option<usize>,
+
+    /// Color disk usage along a gradient from green at MIN to red at MAX on truecolor terminals
+    #[arg(long, value_name = "MIN..MAX", value_parser = units::parse_size_range)]
+    pub size_gradient: Option<SizeRange>,
+
     /// Do not respect .gitignore files
     #[arg(short = 'i', long)]
     pub no_ignore: bool,
 
+    /// Show only what git would track: hidden files are shown, .git and empty directories are
+    /// hidden, and only gitignore rules are respected
+    #[arg(long, conflicts_with = "no_ignore")]
+    pub gitignore_only: bool,
+
     /// Display file icons
     #[arg(short = 'I', long)]
     pub icons: bool,
 
+    /// Which set of icons to display
+    #[arg(long, value_enum, default_value_t, requires = "icons")]
+    pub icon_theme: icon_theme::Type,
+
     /// Show extended metadata and attributes
     #[cfg(unix)]
     #[arg(short, long)]
@@ -107,11 +175,26 @@ pub struct Context {
     #[arg(long)]
     pub nlink: bool,
 
+    /// Show the uid and gid of each file rather than the names of its owner and group
+    #[cfg(unix)]
+    #[arg(long, requires = "long")]
+    pub numeric_owner: bool,
+
     /// Show permissions in numeric octal format instead of symbolic
     #[cfg(unix)]
     #[arg(long, requires = "long")]
     pub octal: bool,
 
+    /// Show the number of extended attributes of each file
+    #[cfg(unix)]
+    #[arg(long, requires = "long")]
+    pub xattr: bool,
+
+    /// When to show the '@' marker for files with extended attributes
+    #[cfg(unix)]
+    #[arg(long, value_enum, default_value_t, requires = "long")]
+    pub xattr_marker: xattr::Marker,
+
     /// Which kind of timestamp to use; modified by default
     #[cfg(unix)]
     #[arg(long, value_enum, requires = "long")]
@@ -122,6 +205,16 @@ pub struct Context {
     #[arg(long = "time-format", value_enum, requires = "long")]
     pub time_format: Option<time::Format>,
 
+    /// Format timestamps using a strftime format string e.g. '%Y/%m/%d'; implies --long
+    #[cfg(unix)]
+    #[arg(long, value_name = "FORMAT", conflicts_with = "time_format")]
+    pub time_custom: Option<String>,
+
+    /// Show created, modified, and accessed timestamps side by side
+    #[cfg(unix)]
+    #[arg(long, requires = "long", conflicts_with = "time")]
+    pub time_all: bool,
+
     /// Maximum depth to display
     #[arg(short = 'L', long, value_name = "NUM")]
     level: Option<usize>,
@@ -138,6 +231,10 @@ pub struct Context {
     #[arg(group = "searching", long, requires = "pattern")]
     pub iglob: bool,
 
+    /// Match the regex or glob against the path relative to the root rather than the file name
+    #[arg(long, requires = "pattern")]
+    pub full_path: bool,
+
     /// Restrict regex or glob search to a particular file-type
     #[arg(short = 't', long, requires = "pattern", value_enum)]
     pub file_type: Option<file::Type>,
@@ -146,22 +243,79 @@ pub struct Context {
     #[arg(short = 'P', long)]
     pub prune: bool,
 
+    /// Omit files smaller than the provided size e.g. 500K, 10M, 1GB; implies '--prune'
+    #[arg(
+        long,
+        value_name = "SIZE",
+        value_parser = units::parse_size,
+        conflicts_with = "suppress_size"
+    )]
+    pub min_size: Option<u64>,
+
+    /// Omit files larger than the provided size e.g. 500K, 10M, 1GB; implies '--prune'
+    #[arg(
+        long,
+        value_name = "SIZE",
+        value_parser = units::parse_size,
+        conflicts_with = "suppress_size"
+    )]
+    pub max_size: Option<u64>,
+
     /// How to sort entries
     #[arg(short, long, value_enum, default_value_t)]
     pub sort: sort::Type,
 
+    /// Secondary sort applied to entries that are equal according to '--sort'
+    #[arg(long, value_enum, value_name = "SORT")]
+    pub sort_then: Option<sort::Type>,
+
+    /// Show at most N entries per directory as ordered by '--sort', summarizing the rest
+    #[arg(
+        long,
+        value_name = "N",
+        conflicts_with_all = ["csv", "folded", "by_extension", "indent"]
+    )]
+    pub max_entries: Option<usize>,
+
+    /// Reverse the order of entries as determined by '--sort' and '--sort-then'
+    #[arg(short, long)]
+    pub reverse: bool,
+
     /// Sort directories before or after all other file types
     #[arg(short = 'D', long, value_enum, default_value_t, default_missing_value = "last", num_args = 0..=1)]
     pub dir_order: dir::Order,
 
-    /// Number of threads to use
+    /// Number of threads to use; 0 uses the available parallelism of the machine
     #[arg(short = 'T', long, default_value_t = Context::num_threads())]
     pub threads: usize,
 
+    /// Number of threads to use to sort the children of directories once traversal completes; 0
+    /// uses the available parallelism of the machine [default: 1]
+    #[arg(long, value_name = "THREADS")]
+    pub assemble_threads: Option<usize>,
+
     /// Report disk usage in binary or SI units
     #[arg(short, long, value_enum, default_value_t)]
     pub unit: PrefixKind,
 
+    /// Print disk usage in human-readable SI units; shorthand for '--human --unit si' though an
+    /// explicit '--unit' takes precedence
+    #[arg(long, conflicts_with = "fixed_unit")]
+    pub si: bool,
+
+    /// Report every disk usage in the provided unit regardless of its magnitude
+    #[arg(
+        long,
+        value_enum,
+        value_name = "UNIT",
+        conflicts_with_all = ["unit", "auto_unit_column"]
+    )]
+    pub fixed_unit: Option<FixedUnit>,
+
+    /// Number of decimal places with which to report human-readable disk usage
+    #[arg(long, value_name = "NUM", default_value_t = 1)]
+    pub scale: usize,
+
     /// Prevent traversal into directories that are on different filesystems
     #[arg(short = 'x', long = "one-file-system")]
     pub same_fs: bool,
@@ -178,6 +332,22 @@ pub struct Context {
     #[arg(long, requires = "hidden")]
     pub no_git: bool,
 
+    /// Hide clutter such as .DS_Store, Thumbs.db, and editor swap and backup files
+    #[arg(long)]
+    pub hide_clutter: bool,
+
+    /// Comma-separated globs to treat as clutter in addition to the built-in ones
+    #[arg(long, requires = "hide_clutter", value_name = "GLOBS")]
+    pub clutter: Option<String>,
+
+    /// Exclude files and directories matching the glob; may be provided multiple times
+    #[arg(long, value_name = "GLOB")]
+    pub exclude: Vec<String>,
+
+    /// Additional ignore file with gitignore syntax; may be provided multiple times
+    #[arg(long, value_name = "PATH")]
+    pub ignore_file: Vec<PathBuf>,
+
     #[arg(long)]
     /// Print completions for a given shell to stdout
     pub completions: Option<clap_complete::Shell>,
@@ -186,6 +356,14 @@ pub struct Context {
     #[arg(long)]
     pub dirs_only: bool,
 
+    /// Only print regular files, keeping the directories that contain them
+    #[arg(long, conflicts_with = "dirs_only")]
+    pub files_only: bool,
+
+    /// Omit regular files that appear to contain binary data
+    #[arg(long)]
+    pub text_only: bool,
+
     /// Don't read configuration file
     #[arg(long)]
     pub no_config: bool,
@@ -202,6 +380,113 @@ pub struct Context {
     #[arg(long)]
     pub truncate: bool,
 
+    /// Don't print a newline after the final line of output
+    #[arg(long)]
+    pub no_trailing_newline: bool,
+
+    /// Write output to the provided file rather than stdout; color is off unless forced
+    #[arg(short = 'o', long, value_name = "PATH")]
+    pub output: Option<PathBuf>,
+
+    /// Write each line as soon as it is rendered rather than buffering the entire output
+    #[arg(long)]
+    pub depth_first: bool,
+
+    /// Draw branches with ASCII characters instead of box drawing characters
+    #[arg(long)]
+    pub ascii: bool,
+
+    /// Print a CSV of every entry's path, size in bytes, file-type, and depth
+    #[arg(long, conflicts_with = "layout")]
+    pub csv: bool,
+
+    /// Print the disk usage of every file as folded stacks for use with flamegraph tooling
+    #[arg(long, conflicts_with_all = ["layout", "csv"])]
+    pub folded: bool,
+
+    /// Print a table of the file count and total disk usage per file extension
+    #[arg(long, conflicts_with_all = ["layout", "csv", "folded"])]
+    pub by_extension: bool,
+
+    /// Print every entry indented by one tab per level of depth followed by its raw disk usage
+    #[arg(long, conflicts_with_all = ["layout", "csv", "folded", "by_extension"])]
+    pub indent: bool,
+
+    /// Print only the N largest files of the entire tree along with their full paths
+    #[arg(
+        long,
+        value_name = "N",
+        conflicts_with_all = ["layout", "csv", "folded", "by_extension", "indent", "dirs_only", "max_entries"]
+    )]
+    pub top: Option<usize>,
+
+    /// Print the path of every file terminated by a NUL byte for use with 'xargs -0'
+    #[arg(
+        long,
+        conflicts_with_all = ["csv", "folded", "by_extension", "indent", "top", "max_entries"]
+    )]
+    pub print0: bool,
+
+    /// Print the tree as a nested Markdown list with directories in bold and without color
+    #[arg(
+        long,
+        conflicts_with_all = ["layout", "csv", "folded", "by_extension", "indent", "top", "print0"]
+    )]
+    pub markdown: bool,
+
+    /// Remove a literal prefix from displayed paths; paths without the prefix are left unchanged
+    #[arg(long, value_name = "STR")]
+    pub strip_prefix: Option<String>,
+
+    /// Show the disk usage of each entry as a percentage of its parent directory's
+    #[arg(long)]
+    pub percent: bool,
+
+    /// Show a sparkline of the disk usages of each directory's children beside its name
+    #[arg(long)]
+    pub sparkline: bool,
+
+    /// Show a bar beside the disk usage of each entry proportional to its parent directory's
+    #[arg(long)]
+    pub bars: bool,
+
+    /// Summarize the total number of directories and files along with their aggregate disk usage
+    #[arg(long)]
+    pub total: bool,
+
+    /// Report the depth and path of the most deeply nested entry
+    #[arg(long)]
+    pub max_depth_stat: bool,
+
+    /// Print the NUM largest entries by disk usage beneath the tree
+    #[arg(long, value_name = "NUM", default_missing_value = "10", num_args = 0..=1)]
+    pub with_summary: Option<usize>,
+
+    /// Print time spent in traversal, assembly, and rendering to stderr
+    #[arg(long)]
+    pub timing: bool,
+
+    /// Print the number of entries walked and skipped, total bytes, and elapsed time to stderr
+    #[arg(long)]
+    pub stats: bool,
+
+    /// Don't report entries that couldn't be read to stderr
+    #[arg(short, long)]
+    pub quiet: bool,
+
+    /// Highlight names of entries nested at or beyond the provided depth
+    #[arg(long, value_name = "NUM")]
+    pub highlight_depth: Option<usize>,
+
+    /// Highlight names of files that have the setuid, setgid, or sticky bit set
+    #[cfg(unix)]
+    #[arg(long)]
+    pub highlight_special: bool,
+
+    /// Substitute file name colors whose contrast ratio against a dark background is below RATIO
+    #[arg(long, value_name = "RATIO")]
+    pub min_contrast: Option<f64>,
+
     //////////////////////////
     /* INTERNAL USAGE BELOW */
     //////////////////////////
@@ -213,6 +498,10 @@ pub struct Context {
     #[clap(skip = tty::stdout_is_tty())]
     pub stdout_is_tty: bool,
 
+    /// Disk usage metrics to compute for each node in addition to the one given by '--disk-usage'
+    #[clap(skip)]
+    pub extra_disk_usages: Vec<DiskUsage>,
+
     /// Restricts column width of size not including units
     #[clap(skip = usize::default())]
     pub max_size_width: usize,
@@ -236,6 +525,11 @@ pub struct Context {
     #[cfg(unix)]
     pub max_block_width: usize,
 
+    /// Restricts column width of the extended attribute count for long view
+    #[clap(skip = usize::default())]
+    #[cfg(unix)]
+    pub max_xattr_width: usize,
+
     /// Restricts column width of file owner for long view
     #[clap(skip = usize::default())]
     #[cfg(unix)]
@@ -249,6 +543,14 @@ pub struct Context {
     /// Width of the terminal emulator's window
     #[clap(skip)]
     pub window_width: Option<usize>,
+
+    /// Branch glyphs set by the `[theme]` table of `.erdtree.toml`
+    #[clap(skip)]
+    pub theme: Option<Glyphs>,
+
+    /// Icons of file extensions set by the `[icons]` table of `.erdtree.toml`
+    #[clap(skip)]
+    pub icon_overrides: Option<HashMap<String, icons::Override>>,
 }
 
 type Predicate = Result<Box<dyn Fn(&DirEntry) -> bool + Send + Sync + 'static>, Error>;
@@ -256,30 +558,134 @@ type Predicate = Result<Box<dyn Fn(&DirEntry) -> bool + Send + Sync + 'static>,
 impl Context {
     /// Initializes [Context], optionally reading in the configuration file to override defaults.
     /// Arguments provided will take precedence over config.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the arguments or config are invalid, if '--scale' exceeds its maximum,
+    /// or if the '--time-format' or the `.erdtree.toml` can't be parsed.
     pub fn try_init() -> Result<Self, Error> {
         Self::compute_args().and_then(|args| {
-            color::no_color_env();
-            Self::from_arg_matches(&args).map_err(Error::Config)
+            color::read_env();
+            let mut ctx = Self::from_arg_matches(&args).map_err(Error::Config)?;
+
+            if ctx.loc {
+                ctx.disk_usage = DiskUsage::Line;
+            }
+
+            if ctx.gitignore_only {
+                ctx.hidden = true;
+                ctx.no_git = true;
+                ctx.prune = true;
+            }
+
+            if ctx.scale > MAX_SCALE {
+                return Err(Error::InvalidScale(ctx.scale));
+            }
+
+            if ctx.si {
+                ctx.human = true;
+
+                if !matches!(args.value_source("unit"), Some(ValueSource::CommandLine)) {
+                    ctx.unit = PrefixKind::Si;
+                }
+            }
+
+            if let Some(unit) = ctx.fixed_unit {
+                ctx.human = true;
+                ctx.unit = unit.prefix_kind();
+            }
+
+            #[cfg(unix)]
+            if let Some(ref format) = ctx.time_custom {
+                time::validate_custom_format(format)?;
+                ctx.long = true;
+            }
+
+            if !ctx.no_config && config::rc::read_config_to_string().is_none() {
+                if let Ok(toml) = config::toml::load() {
+                    ctx.theme = config::toml::parse_theme(&toml)?;
+                    ctx.icon_overrides = config::toml::parse_icons(&toml)?;
+                }
+            }
+
+            Ok(ctx)
         })
     }
 
+    /// The glyphs used to draw branches. '--ascii' takes precedence over the `[theme]` table of
+    /// `.erdtree.toml` which in turn takes precedence over the default box drawing characters.
+    #[must_use]
+    pub fn glyphs(&self) -> Glyphs {
+        if self.ascii {
+            return Glyphs::ascii();
+        }
+
+        self.theme.clone().unwrap_or_default()
+    }
+
     /// Determines whether or not it's appropriate to display color in output based on
-    /// the Coloring, and whether or not stdout is connected to a tty.
-    ///
-    /// If Coloring is Force then this will always evaluate to `false`.
+    /// the Coloring, color related environment variables, and whether or not stdout is connected
+    /// to a tty. Output written to a file via '--output' is treated as not being a tty. See
+    /// [`color::Env::no_color`] for precedence. '--markdown' is never colored.
     pub fn no_color(&self) -> bool {
-        if let Some(Some(var)) = color::NO_COLOR.get() {
-            return !var.is_empty();
+        if self.markdown {
+            return true;
         }
 
-        match self.color {
-            Coloring::Auto if !self.stdout_is_tty => true,
-            Coloring::None => true,
-            Coloring::Auto | Coloring::Force => false,
+        let output_is_tty = self.stdout_is_tty && self.output.is_none();
+
+        color::COLOR_ENV.get().map_or_else(
+            || color::Env::default().no_color(self.color, output_is_tty),
+            |env| env.no_color(self.color, output_is_tty),
+        )
+    }
+
+    /// Whether or not the terminal supports 24-bit color as advertised by the `COLORTERM`
+    /// environment variable.
+    pub fn truecolor(&self) -> bool {
+        color::COLOR_ENV.get().is_some_and(color::Env::truecolor)
+    }
+
+    /// Whether [`Node`]s can be constructed from the file-type reported by [`DirEntry`] alone
+    /// without querying metadata, which is the case when disk usage is suppressed and nothing that
+    /// is rendered or sorted on depends on metadata.
+    ///
+    /// [`Node`]: crate::tree::node::Node
+    #[must_use]
+    pub fn metadata_unneeded(&self) -> bool {
+        #[cfg(unix)]
+        if self.long {
+            return false;
         }
+
+        // Sizes aren't computed when they're suppressed so sorting by size doesn't need metadata.
+        let sorts_without_metadata = |kind| {
+            matches!(
+                kind,
+                sort::Type::Name
+                    | sort::Type::Rname
+                    | sort::Type::Ext
+                    | sort::Type::Size
+                    | sort::Type::Rsize
+            )
+        };
+
+        self.suppress_size
+            && self.no_color()
+            && sorts_without_metadata(self.sort)
+            && self.sort_then.map_or(true, sorts_without_metadata)
+    }
+
+    /// Whether or not a file of the provided `size` falls within the inclusive bounds set by
+    /// '--min-size' and '--max-size'.
+    #[must_use]
+    pub fn size_within_bounds(&self, size: u64) -> bool {
+        self.min_size.map_or(true, |min| size >= min)
+            && self.max_size.map_or(true, |max| size <= max)
     }
 
     /// Returns [Path] of the root directory to be traversed.
+    #[must_use]
     pub fn dir(&self) -> &Path {
         self.dir
             .as_ref()
@@ -287,29 +693,67 @@ impl Context {
     }
 
     /// Returns canonical [Path] of the root directory to be traversed.
+    #[must_use]
     pub fn dir_canonical(&self) -> PathBuf {
         std::fs::canonicalize(self.dir()).unwrap_or_else(|_| self.dir().to_path_buf())
     }
 
+    /// Removes the prefix given by '--strip-prefix' from a path that is to be displayed. Paths that
+    /// don't start with the prefix are returned unchanged.
+    #[must_use]
+    pub fn strip_display_prefix<'a>(&self, path: &'a str) -> &'a str {
+        self.strip_prefix
+            .as_deref()
+            .and_then(|prefix| path.strip_prefix(prefix))
+            .unwrap_or(path)
+    }
+
     /// The max depth to print. Note that all directories are fully traversed to compute file
     /// sizes; this just determines how much to print.
+    #[must_use]
     pub fn level(&self) -> usize {
         self.level.unwrap_or(usize::MAX)
     }
 
+    /// The number of threads to use for disk-reads and parallel processing with `0` resolved to
+    /// the available parallelism of the machine.
+    #[must_use]
+    pub fn threads(&self) -> usize {
+        if self.threads == 0 {
+            Self::num_threads()
+        } else {
+            self.threads
+        }
+    }
+
+    /// The number of threads to use to sort the children of directories after traversal, which is
+    /// a single thread unless '--assemble-threads' is provided. Like '--threads', `0` resolves to
+    /// the available parallelism of the machine. Aggregating disk usage is always serial.
+    #[must_use]
+    pub fn assemble_threads(&self) -> usize {
+        match self.assemble_threads {
+            Some(0) => Self::num_threads(),
+            Some(threads) => threads,
+            None => 1,
+        }
+    }
+
     /// Which timestamp type to use for long view; defaults to modified.
     #[cfg(unix)]
+    #[must_use]
     pub fn time(&self) -> time::Stamp {
         self.time.unwrap_or_default()
     }
 
     /// Which format to use for the timestamp; default by default
     #[cfg(unix)]
+    #[must_use]
     pub fn time_format(&self) -> time::Format {
         self.time_format.unwrap_or_default()
     }
 
     /// Which `FileType` to filter on; defaults to regular file.
+    #[must_use]
     pub fn file_type(&self) -> file::Type {
         self.file_type.unwrap_or_default()
     }
@@ -318,24 +762,39 @@ impl Context {
     /// files, directories will always be included since matched files will need to be bridged back
     /// to the root node somehow. Empty sets not producing an output is handled by [`Tree`].
     ///
+    /// When matching directories a directory matches on its own name, never on a component of the
+    /// root path, and everything within a matching directory is kept. Directories that don't match
+    /// are still traversed and left to be pruned if they don't lead to a match.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if '--pattern' wasn't provided or isn't a valid regular expression.
+    ///
     /// [`Tree`]: crate::tree::Tree
     pub fn regex_predicate(&self) -> Predicate {
         let Some(pattern) = self.pattern.as_ref() else {
             return Err(Error::PatternNotProvided);
         };
 
-        let re = Regex::new(pattern)?;
+        let re = NameMatcher::new(pattern)?;
 
         let file_type = self.file_type();
 
+        let root = self.full_path.then(|| self.dir_canonical());
+
         Ok(match file_type {
             file::Type::Dir => Box::new(move |dir_entry| {
                 let is_dir = dir_entry.file_type().map_or(false, |ft| ft.is_dir());
+
+                // Every directory is traversed so that matches nested beneath directories that
+                // don't match are found; directories that don't lead to a match are pruned.
                 if is_dir {
-                    return Self::ancestor_regex_match(dir_entry.path(), &re, 0);
+                    return true;
                 }
 
-                Self::ancestor_regex_match(dir_entry.path(), &re, 1)
+                let path = Self::match_path(dir_entry, root.as_deref());
+
+                Self::ancestor_regex_match(path, dir_entry.depth(), &re, root.is_some())
             }),
 
             _ => Box::new(move |dir_entry| {
@@ -355,15 +814,29 @@ impl Context {
                     },
                     _ => {},
                 }
-                let file_name = dir_entry.file_name().to_string_lossy();
-                re.is_match(&file_name)
+
+                if root.is_some() {
+                    return re.is_match(Self::match_path(dir_entry, root.as_deref()).as_os_str());
+                }
+
+                re.is_match(dir_entry.file_name())
             }),
         })
     }
 
     /// Predicate used for filtering via globs and file-types.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if '--pattern' wasn't provided or isn't a valid glob.
     pub fn glob_predicate(&self) -> Predicate {
-        let mut builder = OverrideBuilder::new(self.dir());
+        let root = self.full_path.then(|| self.dir_canonical());
+
+        // Globs matched against full paths are anchored to the root so that e.g. `*.rs` only
+        // matches files directly beneath the root as opposed to any file name ending in `.rs`.
+        let mut builder = root
+            .as_deref()
+            .map_or_else(|| OverrideBuilder::new(self.dir()), OverrideBuilder::new);
 
         let mut negated_glob = false;
 
@@ -376,10 +849,18 @@ impl Context {
                 let trim = glob.trim_start();
                 negated_glob = trim.starts_with('!');
 
-                if negated_glob {
-                    builder.add(trim.trim_start_matches('!'))?;
+                let glob = if negated_glob {
+                    trim.trim_start_matches('!')
                 } else {
-                    builder.add(trim)?;
+                    trim
+                };
+
+                for pattern in glob::expand_braces(glob) {
+                    if root.is_some() && !pattern.starts_with('/') {
+                        builder.add(&format!("/{pattern}"))?;
+                    } else {
+                        builder.add(&pattern)?;
+                    }
                 }
             }
 
@@ -391,14 +872,16 @@ impl Context {
         match file_type {
             file::Type::Dir => Ok(Box::new(move |dir_entry| {
                 let is_dir = dir_entry.file_type().map_or(false, |ft| ft.is_dir());
+                let path = Self::match_path(dir_entry, root.as_deref());
+                let full_path = root.is_some();
 
                 if is_dir {
                     if negated_glob {
-                        return !Self::ancestor_glob_match(dir_entry.path(), &overrides, 0);
+                        return !Self::ancestor_glob_match(path, &overrides, 0, full_path);
                     }
-                    return Self::ancestor_glob_match(dir_entry.path(), &overrides, 0);
+                    return Self::ancestor_glob_match(path, &overrides, 0, full_path);
                 }
-                let matched = Self::ancestor_glob_match(dir_entry.path(), &overrides, 1);
+                let matched = Self::ancestor_glob_match(path, &overrides, 1, full_path);
 
                 if negated_glob {
                     !matched
@@ -425,7 +908,8 @@ impl Context {
                     _ => {},
                 }
 
-                let matched = overrides.matched(dir_entry.path(), false);
+                let matched =
+                    overrides.matched(Self::match_path(dir_entry, root.as_deref()), false);
 
                 if negated_glob {
                     !matched.is_whitelist()
@@ -436,20 +920,82 @@ impl Context {
         }
     }
 
-    /// Special override to toggle the visibility of the git directory.
-    pub fn no_git_override(&self) -> Result<Override, Error> {
+    /// Special overrides to toggle the visibility of the git directory, of clutter files, and of
+    /// files matching '--exclude'.
+    ///
+    /// Only negated globs may be added here. Overrides take precedence over `.gitignore` but a
+    /// path that matches none of them falls through to `.gitignore` handling, which keeps user
+    /// negation patterns intact. A single whitelist glob, on the other hand, would cause every
+    /// path it doesn't match to be ignored regardless of `.gitignore`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a glob provided via '--exclude' is invalid.
+    pub fn overrides(&self) -> Result<Override, Error> {
         let mut builder = OverrideBuilder::new(self.dir());
 
         if self.no_git {
             builder.add("!.git")?;
         }
 
+        if self.hide_clutter {
+            let user_clutter = self
+                .clutter
+                .iter()
+                .flat_map(|globs| globs.split(','))
+                .map(str::trim)
+                .filter(|glob| !glob.is_empty());
+
+            for glob in glob::CLUTTER.into_iter().chain(user_clutter) {
+                builder.add(&format!("!{glob}"))?;
+            }
+        }
+
+        let excluded = self
+            .exclude
+            .iter()
+            .flat_map(|glob| glob::expand_braces(glob));
+
+        for glob in excluded {
+            builder.add(&format!("!{glob}"))?;
+        }
+
         Ok(builder.build()?)
     }
 
-    /// Update column width properties.
+    /// Matchers for the files provided via '--ignore-file' followed by the [`ERDTREEIGNORE`] at the
+    /// root of traversal if there is one. Patterns of each file are relative to the directory in
+    /// which the file resides, as is the case with `.gitignore`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an ignore file can't be read or contains an invalid glob.
+    pub fn ignore_files(&self) -> Result<Vec<Gitignore>, Error> {
+        let mut ignore_files = Vec::with_capacity(self.ignore_file.len() + 1);
+
+        let erdtreeignore = self.dir_canonical().join(ERDTREEIGNORE);
+        let erdtreeignore = erdtreeignore.is_file().then_some(erdtreeignore);
+
+        for path in self.ignore_file.iter().chain(erdtreeignore.as_ref()) {
+            let path = path.canonicalize().map_err(ignore::Error::from)?;
+            let root = path.parent().unwrap_or(&path);
+
+            let mut builder = GitignoreBuilder::new(root);
+
+            if let Some(err) = builder.add(&path) {
+                return Err(Error::from(err));
+            }
+
+            ignore_files.push(builder.build()?);
+        }
+
+        Ok(ignore_files)
+    }
+
+    /// Update column width properties. The width of the disk usage column is left as is if it was
+    /// pinned with '--fixed-size-width'.
     pub fn update_column_properties(&mut self, col_props: &column::Properties) {
-        self.max_size_width = col_props.max_size_width;
+        self.max_size_width = self.fixed_size_width.unwrap_or(col_props.max_size_width);
         self.max_size_unit_width = col_props.max_size_unit_width;
 
         #[cfg(unix)]
@@ -459,40 +1005,75 @@ impl Context {
             self.max_nlink_width = col_props.max_nlink_width;
             self.max_block_width = col_props.max_block_width;
             self.max_ino_width = col_props.max_ino_width;
+            self.max_xattr_width = col_props.max_xattr_width;
         }
     }
 
     /// Setter for `window_width` which is set to the current terminal emulator's window width.
     #[inline]
     pub fn set_window_width(&mut self) {
-        self.window_width = crate::tty::get_window_width(self.stdout_is_tty);
+        self.window_width =
+            crate::tty::get_window_width(self.stdout_is_tty && self.output.is_none());
     }
 
     /// Answers whether disk usage is asked to be reported in bytes.
+    #[must_use]
     pub const fn byte_metric(&self) -> bool {
         matches!(self.disk_usage, DiskUsage::Logical | DiskUsage::Physical)
     }
 
+    /// The path of `dir_entry` that patterns are matched against. With '--full-path' this is the
+    /// path relative to `root`, otherwise it's the path as is.
+    #[inline]
+    fn match_path<'a>(dir_entry: &'a DirEntry, root: Option<&Path>) -> &'a Path {
+        let path = dir_entry.path();
+        root.and_then(|root| path.strip_prefix(root).ok())
+            .unwrap_or(path)
+    }
+
     /// Do any of the components of a path match the provided glob? This is used for ensuring that
-    /// all children of a directory that a glob targets gets captured.
+    /// all children of a directory that a glob targets gets captured. With `full_path` the relative
+    /// paths of ancestor directories are matched rather than their names.
     #[inline]
-    fn ancestor_glob_match(path: &Path, ovr: &Override, skip: usize) -> bool {
+    fn ancestor_glob_match(path: &Path, ovr: &Override, skip: usize, full_path: bool) -> bool {
+        if full_path {
+            return Self::relative_ancestors(path)
+                .skip(skip)
+                .any(|p| ovr.matched(p, false).is_whitelist());
+        }
+
         path.components()
             .rev()
             .skip(skip)
             .any(|c| ovr.matched(c, false).is_whitelist())
     }
 
-    /// Like [`Self::ancestor_glob_match`] except uses [Regex] rather than [Override].
+    /// Like [`Self::ancestor_glob_match`] except uses [`NameMatcher`] rather than [Override] and
+    /// only tests the ancestor directories of `path` that are beneath the root, which is `depth`
+    /// levels up from `path`.
     #[inline]
-    fn ancestor_regex_match(path: &Path, re: &Regex, skip: usize) -> bool {
+    fn ancestor_regex_match(path: &Path, depth: usize, re: &NameMatcher, full_path: bool) -> bool {
+        if full_path {
+            return Self::relative_ancestors(path)
+                .skip(1)
+                .any(|p| re.is_match(p.as_os_str()));
+        }
+
         path.components()
             .rev()
-            .skip(skip)
-            .any(|comp| re.is_match(comp.as_os_str().to_string_lossy().borrow()))
+            .take(depth)
+            .skip(1)
+            .any(|comp| re.is_match(comp.as_os_str()))
+    }
+
+    /// `path` followed by each of its ancestors excluding the empty path that represents the root.
+    #[inline]
+    fn relative_ancestors(path: &Path) -> impl Iterator<Item = &Path> {
+        path.ancestors().filter(|p| !p.as_os_str().is_empty())
     }
 
-    /// The default number of threads to use for disk-reads and parallel processing.
+    /// The default number of threads to use for disk-reads and parallel processing, which is also
+    /// what `--threads 0` resolves to.
     fn num_threads() -> usize {
         available_parallelism().map(NonZeroUsize::get).unwrap_or(3)
     }
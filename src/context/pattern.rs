@@ -0,0 +1,75 @@
+use regex::{Error, Regex};
+use std::ffi::OsStr;
+
+#[cfg(unix)]
+use regex::bytes::Regex as BytesRegex;
+
+/// Matches file names against the regular expression provided by '--pattern'. Names that are
+/// valid UTF-8 are matched as strings whereas on Unix the rest are matched against their raw bytes
+/// rather than a lossy conversion, which would otherwise prevent the true bytes from ever being
+/// matched and could produce false matches on the replacement character.
+pub struct NameMatcher {
+    /// Absent if the pattern is only valid when matching bytes e.g. `(?-u:\xff)`.
+    re: Option<Regex>,
+
+    #[cfg(unix)]
+    bytes_re: BytesRegex,
+}
+
+impl NameMatcher {
+    /// Compiles `pattern` for both string and byte oriented matching.
+    #[cfg(unix)]
+    pub fn new(pattern: &str) -> Result<Self, Error> {
+        let bytes_re = BytesRegex::new(pattern)?;
+        let re = Regex::new(pattern).ok();
+
+        Ok(Self { re, bytes_re })
+    }
+
+    /// Compiles `pattern` for string oriented matching.
+    #[cfg(not(unix))]
+    pub fn new(pattern: &str) -> Result<Self, Error> {
+        Regex::new(pattern).map(|re| Self { re: Some(re) })
+    }
+
+    /// Whether or not `name` matches the pattern.
+    #[cfg(unix)]
+    pub fn is_match(&self, name: &OsStr) -> bool {
+        use std::os::unix::ffi::OsStrExt;
+
+        match (name.to_str(), self.re.as_ref()) {
+            (Some(name), Some(re)) => re.is_match(name),
+            _ => self.bytes_re.is_match(name.as_bytes()),
+        }
+    }
+
+    /// Whether or not `name` matches the pattern.
+    #[cfg(not(unix))]
+    pub fn is_match(&self, name: &OsStr) -> bool {
+        self.re
+            .as_ref()
+            .map_or(false, |re| re.is_match(&name.to_string_lossy()))
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn test_non_utf8_name() {
+    use std::os::unix::ffi::OsStrExt;
+
+    let name = OsStr::from_bytes(b"caf\xe9.txt");
+
+    let matcher = NameMatcher::new(r"(?-u:\xe9)\.txt$").unwrap();
+    assert!(matcher.is_match(name));
+    assert!(!matcher.is_match(OsStr::new("cafe.txt")));
+
+    let replacement = NameMatcher::new("\u{FFFD}").unwrap();
+    assert!(
+        !replacement.is_match(name),
+        "Invalid bytes should not match the replacement character"
+    );
+
+    let utf8 = NameMatcher::new(r"^café\.txt$").unwrap();
+    assert!(utf8.is_match(OsStr::new("café.txt")));
+    assert!(!utf8.is_match(name));
+}
@@ -32,4 +32,8 @@ pub enum Type {
 
     /// Sort entries by older to newer Alteration Date
     Rmod,
+
+    /// Sort entries by file extension then by file name; entries without one come first
+    #[value(name = "extension")]
+    Ext,
 }
@@ -0,0 +1,66 @@
+use super::Context;
+use clap::Parser;
+
+#[test]
+fn size_within_bounds() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = Context::try_parse_from(["erd", "--min-size", "1K", "--max-size", "1M"])?;
+
+    assert_eq!(ctx.min_size, Some(1024));
+    assert_eq!(ctx.max_size, Some(1024 * 1024));
+
+    assert!(!ctx.size_within_bounds(1023));
+    assert!(
+        ctx.size_within_bounds(1024),
+        "Lower bound should be inclusive"
+    );
+    assert!(ctx.size_within_bounds(4096));
+    assert!(
+        ctx.size_within_bounds(1024 * 1024),
+        "Upper bound should be inclusive"
+    );
+    assert!(!ctx.size_within_bounds(1024 * 1024 + 1));
+
+    let ctx = Context::try_parse_from(["erd", "--max-size", "100"])?;
+
+    assert!(ctx.size_within_bounds(0));
+    assert!(ctx.size_within_bounds(100));
+    assert!(!ctx.size_within_bounds(101));
+
+    let ctx = Context::try_parse_from(["erd"])?;
+
+    assert!(ctx.size_within_bounds(u64::MAX));
+
+    Ok(())
+}
+
+#[test]
+fn threads() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = Context::try_parse_from(["erd"])?;
+    assert!(ctx.threads() > 0);
+
+    let ctx = Context::try_parse_from(["erd", "--threads", "8"])?;
+    assert_eq!(ctx.threads(), 8);
+
+    let ctx = Context::try_parse_from(["erd", "--threads", "0"])?;
+    assert!(
+        ctx.threads() > 0,
+        "'--threads 0' should resolve to the available parallelism"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn assemble_threads() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = Context::try_parse_from(["erd", "--threads", "5"])?;
+    assert_eq!(
+        ctx.assemble_threads(),
+        1,
+        "Sorting should be serial by default regardless of '--threads'"
+    );
+
+    let ctx = Context::try_parse_from(["erd", "--threads", "5", "--assemble-threads", "2"])?;
+    assert_eq!(ctx.assemble_threads(), 2);
+
+    Ok(())
+}
@@ -1,3 +1,5 @@
+use super::error::Error;
+use chrono::format::{Item, StrftimeItems};
 use clap::ValueEnum;
 
 /// Different types of timestamps available in long-view.
@@ -32,4 +34,25 @@ pub enum Format {
 
     /// Timestamp is shown in DD MMM HH:MM format
     Default,
+
+    /// Timestamp is shown relative to now e.g. 3d ago, falling back to YYYY-MM-DD after a year
+    Relative,
+}
+
+/// Ensures that `format` provided via '--time-custom' is a valid strftime format string so that
+/// invalid formats are reported up front rather than when timestamps are rendered.
+pub fn validate_custom_format(format: &str) -> Result<(), Error> {
+    if StrftimeItems::new(format).any(|item| matches!(item, Item::Error)) {
+        return Err(Error::InvalidTimeFormat(format.to_owned()));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_validate_custom_format() {
+    assert!(validate_custom_format("%Y/%m/%d %H:%M").is_ok());
+    assert!(validate_custom_format("plain text").is_ok());
+    assert!(validate_custom_format("%Y-%Q").is_err());
+    assert!(validate_custom_format("%").is_err());
 }
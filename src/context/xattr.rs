@@ -0,0 +1,26 @@
+use clap::ValueEnum;
+
+/// Enum to determine when the `@` marker denoting extended attributes is shown in long view.
+#[derive(Clone, Copy, Debug, ValueEnum, PartialEq, Eq, Default)]
+pub enum Marker {
+    /// Show the marker for files that have extended attributes
+    #[default]
+    Auto,
+
+    /// Show the marker for every file
+    Always,
+
+    /// Never show the marker
+    Never,
+}
+
+impl Marker {
+    /// Whether or not the marker should be shown for a file given whether it `has_xattrs`.
+    pub const fn show(self, has_xattrs: bool) -> bool {
+        match self {
+            Self::Auto => has_xattrs,
+            Self::Always => true,
+            Self::Never => false,
+        }
+    }
+}
@@ -5,6 +5,7 @@ use std::{
 };
 
 #[derive(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Metric {
     pub value: u64,
 }
@@ -1,30 +1,50 @@
-use super::super::units::{BinPrefix, PrefixKind, SiPrefix, UnitPrefix};
+use super::super::units::{BinPrefix, FixedUnit, PrefixKind, SiPrefix, UnitPrefix};
 use filesize::PathExt;
 use std::{
-    cell::{Ref, RefCell},
     fmt::{self, Display},
     fs::Metadata,
     path::Path,
+    sync::OnceLock,
 };
 
 /// Concerned with measuring file size in bytes, whether logical or physical determined by `kind`.
-/// Binary or SI units used for reporting determined by `prefix_kind`.
+/// Binary or SI units used for reporting determined by `prefix_kind`. Only `value` and `kind` are
+/// serialized as the rest only concern how `value` is displayed.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Metric {
     pub value: u64,
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub human_readable: bool,
-    #[allow(dead_code)]
     kind: MetricKind,
+    #[cfg_attr(feature = "serde", serde(skip))]
     prefix_kind: PrefixKind,
 
+    /// Number of decimal places with which to report `value` when a unit prefix larger than the
+    /// base unit is used.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    scale: usize,
+
+    /// If set, `value` is reported using the unit prefix that is most suitable for
+    /// `prefix_reference` rather than the unit prefix that is most suitable for `value` itself.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    prefix_reference: Option<u64>,
+
+    /// If set, the numeric part of `value` is reported using no more than this many characters.
+    /// See [`Metric::fit`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    max_width: Option<usize>,
+
     /// To prevent allocating the same string twice. We allocate the first time
     /// in [`crate::tree::Tree::update_column_properties`] in order to compute the max column width for
     /// human-readable size and cache it. It will then be used again when preparing the output.
-    cached_display: RefCell<String>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    cached_display: OnceLock<String>,
 }
 
 /// Represents the appropriate method in which to compute bytes. `Logical` represent the total amount
 /// of bytes in a file; `Physical` represents how many bytes are actually used to store the file on
 /// disk.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum MetricKind {
     Logical,
     Physical,
@@ -36,6 +56,7 @@ impl Metric {
         metadata: &Metadata,
         prefix_kind: PrefixKind,
         human_readable: bool,
+        scale: usize,
     ) -> Self {
         let value = metadata.len();
         let kind = MetricKind::Logical;
@@ -45,29 +66,42 @@ impl Metric {
             human_readable,
             kind,
             prefix_kind,
-            cached_display: RefCell::default(),
+            scale,
+            prefix_reference: None,
+            max_width: None,
+            cached_display: OnceLock::new(),
         }
     }
 
     /// Initializes an empty [Metric] used to represent the total amount of bytes of a file.
-    pub fn init_empty_logical(human_readable: bool, prefix_kind: PrefixKind) -> Self {
+    pub fn init_empty_logical(human_readable: bool, prefix_kind: PrefixKind, scale: usize) -> Self {
         Self {
             value: 0,
             human_readable,
             kind: MetricKind::Logical,
             prefix_kind,
-            cached_display: RefCell::default(),
+            scale,
+            prefix_reference: None,
+            max_width: None,
+            cached_display: OnceLock::new(),
         }
     }
 
     /// Initializes an empty [Metric] used to represent the total disk space of a file in bytes.
-    pub fn init_empty_physical(human_readable: bool, prefix_kind: PrefixKind) -> Self {
+    pub fn init_empty_physical(
+        human_readable: bool,
+        prefix_kind: PrefixKind,
+        scale: usize,
+    ) -> Self {
         Self {
             value: 0,
             human_readable,
             kind: MetricKind::Physical,
             prefix_kind,
-            cached_display: RefCell::default(),
+            scale,
+            prefix_reference: None,
+            max_width: None,
+            cached_display: OnceLock::new(),
         }
     }
 
@@ -77,6 +111,7 @@ impl Metric {
         metadata: &Metadata,
         prefix_kind: PrefixKind,
         human_readable: bool,
+        scale: usize,
     ) -> Self {
         let value = path.size_on_disk_fast(metadata).unwrap_or(metadata.len());
         let kind = MetricKind::Physical;
@@ -86,24 +121,127 @@ impl Metric {
             human_readable,
             kind,
             prefix_kind,
-            cached_display: RefCell::default(),
+            scale,
+            prefix_reference: None,
+            max_width: None,
+            cached_display: OnceLock::new(),
         }
     }
 
-    /// Returns an immutable borrow of the `cached_display`.
-    pub fn cached_display(&self) -> Ref<'_, String> {
-        self.cached_display.borrow()
+    /// Whether `value` is the logical or physical size of the file.
+    pub const fn kind(&self) -> &MetricKind {
+        &self.kind
+    }
+
+    /// Forces `value` to be reported using the unit prefix that is most suitable for `reference`.
+    /// This is used to report a column of sizes using a single unit.
+    pub fn set_prefix_reference(&mut self, reference: u64) {
+        self.prefix_reference = Some(reference);
+        self.cached_display.take();
+    }
+
+    /// Forces `value` to always be reported in `unit`, if provided, regardless of its magnitude.
+    /// This is used to report every size in the same unit across separate invocations.
+    pub fn with_fixed_unit(mut self, unit: Option<FixedUnit>) -> Self {
+        if let Some(unit) = unit {
+            self.set_prefix_reference(unit.base_value());
+        }
+        self
+    }
+
+    /// Limits the numeric part of `value` to `width` characters, if provided. This is used to pin
+    /// the width of the disk usage column with '--fixed-size-width'.
+    pub fn with_max_width(mut self, width: Option<usize>) -> Self {
+        self.max_width = width;
+        self.cached_display.take();
+        self
+    }
+
+    /// Returns the value used to determine the unit prefix with which to report `value`.
+    fn prefix_reference(&self) -> u64 {
+        self.prefix_reference.unwrap_or(self.value)
+    }
+
+    /// Returns the `cached_display` if it has been computed.
+    pub fn cached_display(&self) -> Option<&String> {
+        self.cached_display.get()
+    }
+
+    /// The unit prefixes of `prefix_kind` in ascending order along with their base values.
+    fn prefixes(&self) -> [(u64, &'static str); 5] {
+        match self.prefix_kind {
+            PrefixKind::Si => [
+                SiPrefix::Base,
+                SiPrefix::Kilo,
+                SiPrefix::Mega,
+                SiPrefix::Giga,
+                SiPrefix::Tera,
+            ]
+            .map(|prefix| (prefix.base_value(), prefix.as_str())),
+            PrefixKind::Bin => [
+                BinPrefix::Base,
+                BinPrefix::Kibi,
+                BinPrefix::Mebi,
+                BinPrefix::Gibi,
+                BinPrefix::Tebi,
+            ]
+            .map(|prefix| (prefix.base_value(), prefix.as_str())),
+        }
+    }
+
+    /// Makes the numeric part of `display` fit within `width` characters without dropping any of
+    /// its integral digits. Decimal places are dropped first and, if the unit prefix was chosen
+    /// for `value` itself, larger unit prefixes are tried next. A prefix that would round a
+    /// non-zero `value` down to zero is skipped. If nothing fits, the numeric part is replaced
+    /// with an overflow marker.
+    fn fit(&self, display: String, width: usize) -> String {
+        let Some((size, unit)) = display.split_once(' ') else {
+            return display;
+        };
+
+        if size.len() <= width {
+            return display;
+        }
+
+        if self.human_readable {
+            let prefixes = self.prefixes();
+            let current = prefixes
+                .iter()
+                .position(|(_, name)| *name == unit)
+                .unwrap_or_default();
+
+            let candidates = if self.prefix_reference.is_none() {
+                &prefixes[current..]
+            } else {
+                &prefixes[current..=current]
+            };
+
+            for (base_value, name) in candidates {
+                if *base_value == 1 {
+                    continue;
+                }
+
+                let size = self.value as f64 / (*base_value as f64);
+
+                for scale in (0..=self.scale).rev() {
+                    let fitted = format!("{size:.scale$}");
+                    let is_zero = !fitted.bytes().any(|b| matches!(b, b'1'..=b'9'));
+
+                    if fitted.len() <= width && (self.value == 0 || !is_zero) {
+                        return format!("{fitted} {name}");
+                    }
+                }
+            }
+        }
+
+        format!("{} {unit}", super::overflow_marker(width))
     }
 }
 
 impl Display for Metric {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        {
-            let cached_display = self.cached_display();
-
-            if cached_display.len() > 0 {
-                return write!(f, "{cached_display}");
-            }
+        if let Some(cached_display) = self.cached_display() {
+            return write!(f, "{cached_display}");
         }
 
         let value = self.value as f64;
@@ -111,14 +249,14 @@ impl Display for Metric {
         let display = match self.prefix_kind {
             PrefixKind::Si => {
                 if self.human_readable {
-                    let unit = SiPrefix::from(self.value);
+                    let unit = SiPrefix::from(self.prefix_reference());
 
                     if unit == SiPrefix::Base {
                         format!("{} {unit}", self.value)
                     } else {
                         let base_value = unit.base_value();
                         let size = value / (base_value as f64);
-                        format!("{size:.1} {unit}")
+                        format!("{size:.scale$} {unit}", scale = self.scale)
                     }
                 } else {
                     format!("{} {}", self.value, SiPrefix::Base)
@@ -126,14 +264,14 @@ impl Display for Metric {
             },
             PrefixKind::Bin => {
                 if self.human_readable {
-                    let unit = BinPrefix::from(self.value);
+                    let unit = BinPrefix::from(self.prefix_reference());
 
                     if unit == BinPrefix::Base {
                         format!("{} {unit}", self.value)
                     } else {
                         let base_value = unit.base_value();
                         let size = value / (base_value as f64);
-                        format!("{size:.1} {unit}")
+                        format!("{size:.scale$} {unit}", scale = self.scale)
                     }
                 } else {
                     format!("{} {}", self.value, BinPrefix::Base)
@@ -141,11 +279,12 @@ impl Display for Metric {
             },
         };
 
-        write!(f, "{display}")?;
-
-        self.cached_display.replace(display);
+        let display = match self.max_width {
+            Some(width) => self.fit(display, width),
+            None => display,
+        };
 
-        Ok(())
+        write!(f, "{}", self.cached_display.get_or_init(|| display))
     }
 }
 
@@ -156,7 +295,10 @@ fn test_metric() {
         kind: MetricKind::Logical,
         human_readable: false,
         prefix_kind: PrefixKind::Bin,
-        cached_display: RefCell::<String>::default(),
+        scale: 1,
+        prefix_reference: None,
+        max_width: None,
+        cached_display: OnceLock::new(),
     };
     assert_eq!(format!("{metric}"), "100 B");
 
@@ -165,7 +307,10 @@ fn test_metric() {
         kind: MetricKind::Logical,
         human_readable: true,
         prefix_kind: PrefixKind::Si,
-        cached_display: RefCell::<String>::default(),
+        scale: 1,
+        prefix_reference: None,
+        max_width: None,
+        cached_display: OnceLock::new(),
     };
     assert_eq!(format!("{metric}"), "1.0 KB");
 
@@ -174,7 +319,10 @@ fn test_metric() {
         kind: MetricKind::Logical,
         human_readable: true,
         prefix_kind: PrefixKind::Bin,
-        cached_display: RefCell::<String>::default(),
+        scale: 1,
+        prefix_reference: None,
+        max_width: None,
+        cached_display: OnceLock::new(),
     };
     assert_eq!(format!("{metric}"), "1000 B");
 
@@ -183,7 +331,10 @@ fn test_metric() {
         kind: MetricKind::Logical,
         human_readable: true,
         prefix_kind: PrefixKind::Bin,
-        cached_display: RefCell::<String>::default(),
+        scale: 1,
+        prefix_reference: None,
+        max_width: None,
+        cached_display: OnceLock::new(),
     };
     assert_eq!(format!("{metric}"), "1.0 KiB");
 
@@ -192,7 +343,10 @@ fn test_metric() {
         kind: MetricKind::Logical,
         human_readable: true,
         prefix_kind: PrefixKind::Bin,
-        cached_display: RefCell::<String>::default(),
+        scale: 1,
+        prefix_reference: None,
+        max_width: None,
+        cached_display: OnceLock::new(),
     };
     assert_eq!(format!("{metric}"), "1.0 MiB");
 
@@ -201,7 +355,124 @@ fn test_metric() {
         kind: MetricKind::Logical,
         human_readable: false,
         prefix_kind: PrefixKind::Bin,
-        cached_display: RefCell::<String>::default(),
+        scale: 1,
+        prefix_reference: None,
+        max_width: None,
+        cached_display: OnceLock::new(),
     };
     assert_eq!(format!("{metric}"), "123454 B");
 }
+
+#[test]
+fn test_metric_prefix_reference() {
+    let mut metric = Metric {
+        value: 100,
+        kind: MetricKind::Logical,
+        human_readable: true,
+        prefix_kind: PrefixKind::Bin,
+        scale: 1,
+        prefix_reference: None,
+        max_width: None,
+        cached_display: OnceLock::new(),
+    };
+    assert_eq!(format!("{metric}"), "100 B");
+
+    metric.set_prefix_reference(2_u64.pow(20));
+    assert_eq!(format!("{metric}"), "0.0 MiB");
+
+    metric.set_prefix_reference(2_u64.pow(10));
+    assert_eq!(format!("{metric}"), "0.1 KiB");
+}
+
+#[test]
+fn test_metric_fixed_unit() {
+    let metric = Metric {
+        value: 3 * 2_u64.pow(30),
+        kind: MetricKind::Logical,
+        human_readable: true,
+        prefix_kind: PrefixKind::Bin,
+        scale: 1,
+        prefix_reference: None,
+        max_width: None,
+        cached_display: OnceLock::new(),
+    };
+    assert_eq!(
+        format!("{}", metric.with_fixed_unit(Some(FixedUnit::Mib))),
+        "3072.0 MiB"
+    );
+
+    let metric = Metric {
+        value: 1500,
+        kind: MetricKind::Logical,
+        human_readable: true,
+        prefix_kind: PrefixKind::Si,
+        scale: 1,
+        prefix_reference: None,
+        max_width: None,
+        cached_display: OnceLock::new(),
+    };
+    assert_eq!(
+        format!("{}", metric.with_fixed_unit(Some(FixedUnit::Mb))),
+        "0.0 MB"
+    );
+
+    let metric = Metric {
+        value: 2048,
+        kind: MetricKind::Logical,
+        human_readable: true,
+        prefix_kind: PrefixKind::Bin,
+        scale: 1,
+        prefix_reference: None,
+        max_width: None,
+        cached_display: OnceLock::new(),
+    };
+    assert_eq!(
+        format!("{}", metric.with_fixed_unit(Some(FixedUnit::B))),
+        "2048 B"
+    );
+}
+
+#[test]
+fn test_metric_scale() {
+    let metric = |scale| Metric {
+        value: 1_234_567,
+        kind: MetricKind::Logical,
+        human_readable: true,
+        prefix_kind: PrefixKind::Si,
+        scale,
+        prefix_reference: None,
+        max_width: None,
+        cached_display: OnceLock::new(),
+    };
+
+    assert_eq!(format!("{}", metric(0)), "1 MB");
+    assert_eq!(format!("{}", metric(3)), "1.235 MB");
+}
+
+#[test]
+fn test_metric_max_width() {
+    let metric = |value, human_readable, scale, width| Metric {
+        value,
+        kind: MetricKind::Logical,
+        human_readable,
+        prefix_kind: PrefixKind::Bin,
+        scale,
+        prefix_reference: None,
+        max_width: Some(width),
+        cached_display: OnceLock::new(),
+    };
+
+    assert_eq!(format!("{}", metric(308, false, 1, 3)), "308 B");
+    assert_eq!(format!("{}", metric(308, false, 1, 2)), ">9 B");
+    assert_eq!(format!("{}", metric(308, true, 1, 2)), ">9 B");
+    assert_eq!(format!("{}", metric(2000, true, 3, 3)), "2.0 KiB");
+    assert_eq!(format!("{}", metric(2000, true, 3, 1)), "2 KiB");
+    assert_eq!(format!("{}", metric(1000, true, 1, 3)), "1.0 KiB");
+    assert_eq!(format!("{}", metric(0, true, 1, 1)), "0 B");
+
+    let pinned = metric(3 * 2_u64.pow(30), true, 1, 4).with_fixed_unit(Some(FixedUnit::Mib));
+    assert_eq!(format!("{pinned}"), "3072 MiB");
+
+    let pinned = metric(3 * 2_u64.pow(30), true, 1, 3).with_fixed_unit(Some(FixedUnit::Mib));
+    assert_eq!(format!("{pinned}"), ">99 MiB");
+}
@@ -5,22 +5,51 @@ use std::{
     path::Path,
 };
 
+/// Extensions of files that are considered source files by '--loc'.
+const SOURCE_EXTENSIONS: &[&str] = &[
+    "bash", "c", "cc", "cjs", "clj", "cljs", "cpp", "cs", "css", "cxx", "dart", "el", "erl", "ex",
+    "exs", "fish", "fs", "fsx", "go", "h", "hh", "hpp", "hrl", "hs", "html", "java", "jl", "js",
+    "jsx", "kt", "kts", "less", "lisp", "lua", "m", "mjs", "ml", "mli", "mm", "nim", "php", "pl",
+    "pm", "ps1", "py", "r", "rb", "rs", "sass", "scala", "scm", "scss", "sh", "sql", "svelte",
+    "swift", "ts", "tsx", "vue", "zig", "zsh",
+];
+
+/// Whether or not the file at `path` has an extension recognized as that of a source file.
+pub fn is_source_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| SOURCE_EXTENSIONS.contains(&ext.to_ascii_lowercase().as_str()))
+}
+
 /// Concerned with measuring file size using line count as a metric.
 #[derive(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Metric {
     pub value: u64,
 }
 
 impl Metric {
     /// Reads in contents of a file given by `path` and attempts to compute the total number of
-    /// lines in that file. If a file is not UTF-8 encoded as in the case of a binary jpeg file
-    /// then `None` will be returned.
+    /// lines in that file. Files that aren't UTF-8 encoded as in the case of a binary jpeg file
+    /// are counted by their raw newline-delimited lines which may very well be zero. `None` is
+    /// returned if the file can't be read.
     pub fn init(path: impl AsRef<Path>) -> Option<Self> {
-        let data = fs::read_to_string(path.as_ref()).ok()?;
+        let data = fs::read(path.as_ref()).ok()?;
 
-        let lines = data.lines().count();
+        u64::try_from(Self::count(&data))
+            .map(|value| Self { value })
+            .ok()
+    }
+
+    /// Counts lines the same way as [`str::lines`] but without requiring `data` to be valid UTF-8:
+    /// A trailing line without a terminating newline is counted as well.
+    fn count(data: &[u8]) -> usize {
+        let newlines = data.iter().filter(|&&byte| byte == b'\n').count();
 
-        u64::try_from(lines).map(|value| Self { value }).ok()
+        match data.last() {
+            Some(&byte) if byte != b'\n' => newlines + 1,
+            _ => newlines,
+        }
     }
 }
 
@@ -43,3 +72,19 @@ fn test_line_count() {
 
     assert_eq!(metric.value, 4);
 }
+
+#[test]
+fn test_raw_line_count() {
+    assert_eq!(Metric::count(b""), 0);
+    assert_eq!(Metric::count(b"\xff\xfe\x00"), 1);
+    assert_eq!(Metric::count(b"\xff\n\xfe\n"), 2);
+    assert_eq!(Metric::count(b"one\ntwo"), "one\ntwo".lines().count());
+}
+
+#[test]
+fn test_is_source_file() {
+    assert!(is_source_file(Path::new("src/main.rs")));
+    assert!(is_source_file(Path::new("Makefile.PY")));
+    assert!(!is_source_file(Path::new("assets/logo.png")));
+    assert!(!is_source_file(Path::new("Makefile")));
+}
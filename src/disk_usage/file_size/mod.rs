@@ -22,7 +22,17 @@ pub mod word_count;
 #[cfg(unix)]
 pub const BLOCK_SIZE_BYTES: u16 = 512;
 
+/// What's reported in place of a disk usage that doesn't fit within `width` characters: a '>'
+/// followed by the largest number that does, e.g. `>99` for a width of three.
+pub fn overflow_marker(width: usize) -> String {
+    std::iter::once('>')
+        .chain(std::iter::repeat('9'))
+        .take(width)
+        .collect()
+}
+
 /// Represents all the different ways in which a filesize could be reported using various metrics.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum FileSize {
     Word(word_count::Metric),
     Line(line_count::Metric),
@@ -32,7 +42,7 @@ pub enum FileSize {
 }
 
 /// Determines between logical or physical size for display
-#[derive(Copy, Clone, Debug, ValueEnum, Default)]
+#[derive(Copy, Clone, Debug, ValueEnum, PartialEq, Eq, Default)]
 pub enum DiskUsage {
     /// How many bytes does a file contain
     Logical,
@@ -65,6 +75,57 @@ impl FileSize {
             Self::Block(metric) => metric.value,
         }
     }
+
+    /// The file size in bytes. Line and word counts have no meaningful size in bytes and yield
+    /// `None`.
+    pub fn bytes(&self) -> Option<u64> {
+        match self {
+            Self::Byte(metric) => Some(metric.value),
+            Self::Line(_) | Self::Word(_) => None,
+
+            #[cfg(unix)]
+            Self::Block(metric) => Some(metric.value * u64::from(BLOCK_SIZE_BYTES)),
+        }
+    }
+
+    /// The [`DiskUsage`] metric that [`FileSize`] was measured with.
+    pub const fn disk_usage(&self) -> DiskUsage {
+        match self {
+            Self::Byte(metric) => match metric.kind() {
+                byte::MetricKind::Logical => DiskUsage::Logical,
+                byte::MetricKind::Physical => DiskUsage::Physical,
+            },
+            Self::Line(_) => DiskUsage::Line,
+            Self::Word(_) => DiskUsage::Word,
+
+            #[cfg(unix)]
+            Self::Block(_) => DiskUsage::Block,
+        }
+    }
+
+    /// Initializes an empty [`FileSize`] for the given `disk_usage`, using [`Context`] to determine
+    /// how bytes should be reported.
+    pub fn empty(disk_usage: DiskUsage, ctx: &Context) -> Self {
+        use DiskUsage::{Line, Logical, Physical, Word};
+
+        match disk_usage {
+            Logical => Self::Byte(
+                byte::Metric::init_empty_logical(ctx.human, ctx.unit, ctx.scale)
+                    .with_fixed_unit(ctx.fixed_unit)
+                    .with_max_width(ctx.fixed_size_width),
+            ),
+            Physical => Self::Byte(
+                byte::Metric::init_empty_physical(ctx.human, ctx.unit, ctx.scale)
+                    .with_fixed_unit(ctx.fixed_unit)
+                    .with_max_width(ctx.fixed_size_width),
+            ),
+            Line => Self::Line(line_count::Metric::default()),
+            Word => Self::Word(word_count::Metric::default()),
+
+            #[cfg(unix)]
+            DiskUsage::Block => Self::Block(block::Metric::default()),
+        }
+    }
 }
 
 impl AddAssign<&Self> for FileSize {
@@ -82,17 +143,7 @@ impl AddAssign<&Self> for FileSize {
 
 impl From<&Context> for FileSize {
     fn from(ctx: &Context) -> Self {
-        use DiskUsage::{Line, Logical, Physical, Word};
-
-        match ctx.disk_usage {
-            Logical => Self::Byte(byte::Metric::init_empty_logical(ctx.human, ctx.unit)),
-            Physical => Self::Byte(byte::Metric::init_empty_physical(ctx.human, ctx.unit)),
-            Line => Self::Line(line_count::Metric::default()),
-            Word => Self::Word(word_count::Metric::default()),
-
-            #[cfg(unix)]
-            DiskUsage::Block => Self::Block(block::Metric::default()),
-        }
+        Self::empty(ctx.disk_usage, ctx)
     }
 }
 
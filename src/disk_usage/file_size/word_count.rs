@@ -7,6 +7,7 @@ use std::{
 
 /// Concerned with measuring file size using word count as a metric.
 #[derive(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Metric {
     pub value: u64,
 }
@@ -15,6 +15,73 @@ pub enum PrefixKind {
     Si,
 }
 
+/// A single unit in which to report every disk usage regardless of its magnitude.
+#[derive(Copy, Clone, Debug, ValueEnum, PartialEq, Eq)]
+pub enum FixedUnit {
+    /// Bytes
+    #[value(name = "B")]
+    B,
+
+    /// Kibibytes, 1024 bytes
+    #[value(name = "KiB")]
+    Kib,
+
+    /// Mebibytes, 1024 kibibytes
+    #[value(name = "MiB")]
+    Mib,
+
+    /// Gibibytes, 1024 mebibytes
+    #[value(name = "GiB")]
+    Gib,
+
+    /// Tebibytes, 1024 gibibytes
+    #[value(name = "TiB")]
+    Tib,
+
+    /// Kilobytes, 1000 bytes
+    #[value(name = "KB")]
+    Kb,
+
+    /// Megabytes, 1000 kilobytes
+    #[value(name = "MB")]
+    Mb,
+
+    /// Gigabytes, 1000 megabytes
+    #[value(name = "GB")]
+    Gb,
+
+    /// Terabytes, 1000 gigabytes
+    #[value(name = "TB")]
+    Tb,
+}
+
+impl FixedUnit {
+    /// The family of prefixes that the unit belongs to. Plain bytes are shared by both and are
+    /// considered binary.
+    pub const fn prefix_kind(self) -> PrefixKind {
+        match self {
+            Self::B | Self::Kib | Self::Mib | Self::Gib | Self::Tib => PrefixKind::Bin,
+            Self::Kb | Self::Mb | Self::Gb | Self::Tb => PrefixKind::Si,
+        }
+    }
+}
+
+impl UnitPrefix for FixedUnit {
+    fn base_value(&self) -> u64 {
+        match self {
+            Self::B => BinPrefix::Base.base_value(),
+            Self::Kib => BinPrefix::Kibi.base_value(),
+            Self::Mib => BinPrefix::Mebi.base_value(),
+            Self::Gib => BinPrefix::Gibi.base_value(),
+            Self::Tib => BinPrefix::Tebi.base_value(),
+            Self::Kb => SiPrefix::Kilo.base_value(),
+            Self::Mb => SiPrefix::Mega.base_value(),
+            Self::Gb => SiPrefix::Giga.base_value(),
+            Self::Tb => SiPrefix::Tera.base_value(),
+        }
+    }
+}
+
 /// Binary prefixes.
 #[derive(Debug, PartialEq, Eq)]
 pub enum BinPrefix {
@@ -37,7 +104,7 @@ pub enum SiPrefix {
 
 impl SiPrefix {
     /// Returns the human readable representation of the SI prefix.
-    pub const fn as_str(&self) -> &str {
+    pub const fn as_str(&self) -> &'static str {
         match self {
             Self::Base => "B",
             Self::Kilo => "KB",
@@ -50,7 +117,7 @@ impl SiPrefix {
 
 impl BinPrefix {
     /// Returns the human readable representation of the binary prefix.
-    pub const fn as_str(&self) -> &str {
+    pub const fn as_str(&self) -> &'static str {
         match self {
             Self::Base => "B",
             Self::Kibi => "KiB",
@@ -138,3 +205,104 @@ impl Display for SiPrefix {
         write!(f, "{}", self.as_str())
     }
 }
+
+/// Parses a human-readable size such as `500K`, `10MiB`, or `1.5GB` into bytes. Single-letter
+/// prefixes and prefixes ending in 'iB' are interpreted as binary prefixes whereas prefixes ending
+/// in 'B' are interpreted as SI prefixes. Sizes without a prefix are taken to be in bytes.
+pub fn parse_size(input: &str) -> Result<u64, String> {
+    let trimmed = input.trim();
+
+    let split = trimmed
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(trimmed.len());
+
+    let (number, prefix) = trimmed.split_at(split);
+
+    let number = number
+        .parse::<f64>()
+        .map_err(|_e| format!("'{input}' is not a valid size"))?;
+
+    let base_value = match prefix.trim().to_ascii_uppercase().as_str() {
+        "" | "B" => BinPrefix::Base.base_value(),
+        "K" | "KIB" => BinPrefix::Kibi.base_value(),
+        "M" | "MIB" => BinPrefix::Mebi.base_value(),
+        "G" | "GIB" => BinPrefix::Gibi.base_value(),
+        "T" | "TIB" => BinPrefix::Tebi.base_value(),
+        "KB" => SiPrefix::Kilo.base_value(),
+        "MB" => SiPrefix::Mega.base_value(),
+        "GB" => SiPrefix::Giga.base_value(),
+        "TB" => SiPrefix::Tera.base_value(),
+        _ => return Err(format!("'{input}' has an unrecognized unit")),
+    };
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    Ok((number * base_value as f64).round() as u64)
+}
+
+/// An inclusive range of sizes in bytes given by '--size-gradient'.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SizeRange {
+    pub min: u64,
+    pub max: u64,
+}
+
+impl SizeRange {
+    /// Where `size` falls within the range from `0.0` at `min` to `1.0` at `max`. Sizes outside of
+    /// the range are clamped to its endpoints.
+    pub fn position(&self, size: u64) -> f64 {
+        let clamped = size.clamp(self.min, self.max);
+        (clamped - self.min) as f64 / (self.max - self.min) as f64
+    }
+}
+
+/// Parses a range of human-readable sizes such as `1K..100M` into a [`SizeRange`]. Each end of the
+/// range is parsed by [`parse_size`] and the lower end must be smaller than the upper end.
+pub fn parse_size_range(input: &str) -> Result<SizeRange, String> {
+    let Some((min, max)) = input.split_once("..") else {
+        return Err(format!("'{input}' is not a range of the form <MIN>..<MAX>"));
+    };
+
+    let (min, max) = (parse_size(min)?, parse_size(max)?);
+
+    if min >= max {
+        return Err(format!(
+            "The lower end of '{input}' must be smaller than the upper end"
+        ));
+    }
+
+    Ok(SizeRange { min, max })
+}
+
+#[test]
+fn test_parse_size() {
+    assert_eq!(parse_size("123"), Ok(123));
+    assert_eq!(parse_size("123B"), Ok(123));
+    assert_eq!(parse_size("500K"), Ok(500 * 1024));
+    assert_eq!(parse_size("10M"), Ok(10 * 1024 * 1024));
+    assert_eq!(parse_size("10MiB"), Ok(10 * 1024 * 1024));
+    assert_eq!(parse_size("10MB"), Ok(10_000_000));
+    assert_eq!(parse_size("1.5 GiB"), Ok(1_610_612_736));
+    assert_eq!(parse_size("2t"), Ok(2 * 1024_u64.pow(4)));
+    assert!(parse_size("ten").is_err());
+    assert!(parse_size("10X").is_err());
+    assert!(parse_size("").is_err());
+}
+
+#[test]
+fn test_parse_size_range() {
+    assert_eq!(
+        parse_size_range("1K..2M"),
+        Ok(SizeRange {
+            min: 1024,
+            max: 2 * 1024 * 1024
+        })
+    );
+    assert!(parse_size_range("2M..1K").is_err());
+    assert!(parse_size_range("1K").is_err());
+    assert!(parse_size_range("..1K").is_err());
+
+    let range = parse_size_range("100..200").unwrap();
+    assert!((range.position(50) - 0.0).abs() < f64::EPSILON);
+    assert!((range.position(150) - 0.5).abs() < f64::EPSILON);
+    assert!((range.position(500) - 1.0).abs() < f64::EPSILON);
+}
@@ -0,0 +1,64 @@
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    path::{Path, PathBuf},
+};
+use zip::{result::ZipResult, ZipArchive};
+
+/// A file or directory inside of an archive.
+pub struct Entry {
+    /// Path of the entry relative to the root of the archive.
+    pub path: PathBuf,
+
+    /// Uncompressed size of the entry in bytes. Directories have no size.
+    pub size: Option<u64>,
+}
+
+impl Entry {
+    /// Whether or not the entry is a directory.
+    pub const fn is_dir(&self) -> bool {
+        self.size.is_none()
+    }
+}
+
+/// Whether or not the file at `path` is an archive whose entries can be listed, going by its
+/// extension.
+pub fn is_archive(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("zip"))
+}
+
+/// Reads the entries of the zip archive at `path` from its central directory without
+/// decompressing anything. Directories that are only implied by the paths of the files within
+/// them are included as well and every directory precedes its contents. Entries whose names would
+/// escape the archive, e.g. `../foo`, are left out.
+pub fn zip_entries(path: &Path) -> ZipResult<Vec<Entry>> {
+    let mut archive = ZipArchive::new(File::open(path)?)?;
+    let mut entries = BTreeMap::new();
+
+    for i in 0..archive.len() {
+        let file = archive.by_index_raw(i)?;
+
+        let Some(name) = file.enclosed_name().map(Path::to_path_buf) else {
+            continue;
+        };
+
+        for ancestor in name.ancestors().skip(1) {
+            if !ancestor.as_os_str().is_empty() {
+                entries.entry(ancestor.to_path_buf()).or_insert(None);
+            }
+        }
+
+        let size = (!file.is_dir()).then(|| file.size());
+
+        entries.insert(name, size);
+    }
+
+    // Paths are ordered component-wise so every directory sorts before its contents.
+    let entries = entries
+        .into_iter()
+        .map(|(path, size)| Entry { path, size })
+        .collect();
+
+    Ok(entries)
+}
@@ -2,6 +2,7 @@ use std::{convert::TryFrom, fs::Metadata};
 
 /// Represents a file's underlying inode.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Inode {
     pub ino: u64,
     pub dev: u64,
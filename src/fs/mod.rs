@@ -1,5 +1,14 @@
 use ignore::DirEntry;
-use std::{fs, path::PathBuf};
+use std::{
+    collections::HashSet,
+    fs::{self, File},
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+/// Reading the entries of archives without extracting them.
+#[cfg(feature = "zip")]
+pub mod archive;
 
 /// Operations pertaining to underlying inodes of files.
 pub mod inode;
@@ -16,6 +25,9 @@ pub mod xattr;
 #[cfg(unix)]
 pub mod ug;
 
+/// How many leading bytes of a file are inspected to determine whether or not it's binary.
+const BINARY_SNIFF_LEN: u64 = 1024;
+
 /// Returns the path to the target of the soft link. Returns `None` if provided `dir_entry` isn't a
 /// symlink.
 pub fn symlink_target(dir_entry: &DirEntry) -> Option<PathBuf> {
@@ -26,3 +38,41 @@ pub fn symlink_target(dir_entry: &DirEntry) -> Option<PathBuf> {
         .ok()
         .flatten()
 }
+
+/// Number of symlinks that were traversed to arrive at `dir_entry` from the root, that is the
+/// number of its ancestors beneath the root that are among the symlinked directories in `links`.
+/// `dir_entry` itself isn't counted. Nothing is stat'd as `links` is gathered during traversal.
+pub fn link_hops(dir_entry: &DirEntry, links: &HashSet<PathBuf>) -> usize {
+    if links.is_empty() {
+        return 0;
+    }
+
+    dir_entry
+        .path()
+        .ancestors()
+        .skip(1)
+        .take(dir_entry.depth().saturating_sub(1))
+        .filter(|ancestor| links.contains(*ancestor))
+        .count()
+}
+
+/// Cheap heuristic to determine whether or not the file at `path` contains binary data: if a NUL
+/// byte is found within the first [`BINARY_SNIFF_LEN`] bytes then the file is considered binary.
+/// Files that cannot be read are not considered binary.
+pub fn is_binary(path: &Path) -> bool {
+    let Ok(file) = File::open(path) else {
+        return false;
+    };
+
+    let mut prefix = Vec::new();
+
+    if file
+        .take(BINARY_SNIFF_LEN)
+        .read_to_end(&mut prefix)
+        .is_err()
+    {
+        return false;
+    }
+
+    prefix.contains(&0)
+}
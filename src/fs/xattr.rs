@@ -1,5 +1,5 @@
 use ignore::DirEntry;
-use std::{os::unix::ffi::OsStrExt, path::Path, ptr};
+use std::{ffi::CString, os::unix::ffi::OsStrExt, path::Path, ptr};
 
 /// Allow extended attributes to be queried directly from the directory entry.
 impl ExtendedAttr for DirEntry {
@@ -17,6 +17,11 @@ pub trait ExtendedAttr {
     fn has_xattrs(&self) -> bool {
         unsafe { has_xattrs(self.path()) }
     }
+
+    /// Queries the filesystem for the number of extended attributes of the implementor's path.
+    fn xattr_count(&self) -> usize {
+        unsafe { xattr_count(self.path()) }
+    }
 }
 
 /// Checks to see if a directory entry referred to by `path` has extended attributes. If the file
@@ -24,11 +29,11 @@ pub trait ExtendedAttr {
 unsafe fn has_xattrs(path: &Path) -> bool {
     use libc::{c_char, listxattr};
 
-    let path_ptr = {
-        let slice = path.as_os_str().as_bytes();
-        let slice_ptr = slice.as_ptr();
-        slice_ptr.cast::<c_char>()
+    // The path handed to `listxattr` must be null-terminated.
+    let Ok(path) = CString::new(path.as_os_str().as_bytes()) else {
+        return false;
     };
+    let path_ptr = path.as_ptr();
 
     #[cfg(not(target_os = "macos"))]
     return 0 < listxattr(path_ptr, ptr::null_mut::<c_char>(), 0);
@@ -36,3 +41,45 @@ unsafe fn has_xattrs(path: &Path) -> bool {
     #[cfg(target_os = "macos")]
     return 0 < listxattr(path_ptr, ptr::null_mut::<c_char>(), 0, 0);
 }
+
+/// Counts the extended attributes of the file referred to by `path`. The names of the attributes
+/// are listed into a buffer as a sequence of null-terminated strings which are then counted. If
+/// the file at the provided `path` is a symlink the file it points to is interrogated.
+unsafe fn xattr_count(path: &Path) -> usize {
+    use libc::{c_char, listxattr};
+
+    let Ok(path) = CString::new(path.as_os_str().as_bytes()) else {
+        return 0;
+    };
+    let path_ptr = path.as_ptr();
+
+    #[cfg(not(target_os = "macos"))]
+    let size = listxattr(path_ptr, ptr::null_mut::<c_char>(), 0);
+
+    #[cfg(target_os = "macos")]
+    let size = listxattr(path_ptr, ptr::null_mut::<c_char>(), 0, 0);
+
+    let Ok(size) = usize::try_from(size) else {
+        return 0;
+    };
+
+    if size == 0 {
+        return 0;
+    }
+
+    let mut names = vec![0_u8; size];
+    let names_ptr = names.as_mut_ptr().cast::<c_char>();
+
+    #[cfg(not(target_os = "macos"))]
+    let size = listxattr(path_ptr, names_ptr, names.len());
+
+    #[cfg(target_os = "macos")]
+    let size = listxattr(path_ptr, names_ptr, names.len(), 0);
+
+    // Attributes may have been added between calls in which case the buffer is too small.
+    let Ok(size) = usize::try_from(size) else {
+        return 0;
+    };
+
+    names[..size].iter().filter(|&&byte| byte == 0).count()
+}
@@ -0,0 +1,146 @@
+use crate::hash;
+use ignore::DirEntry;
+use once_cell::sync::Lazy;
+use std::{
+    collections::HashMap,
+    ffi::{OsStr, OsString},
+    path::Path,
+};
+
+/// Computes an emoji icon with given parameters. Emoji carry their own colors so no styling is
+/// applied. Precedence is the same as [`super::fs::compute`].
+pub fn compute(entry: &DirEntry, link_target: Option<&Path>) -> &'static str {
+    let icon = entry
+        .file_type()
+        .and_then(super::file_type_key)
+        .and_then(|key| FILE_TYPE_EMOJI_MAP.get(key).copied());
+
+    if let Some(i) = icon {
+        return i;
+    }
+
+    if let Some(i) = FILE_NAME_EMOJI_MAP.get(entry.file_name()).copied() {
+        return i;
+    }
+
+    let ext = match link_target {
+        Some(target) if entry.path_is_symlink() => target.extension(),
+        _ => entry.path().extension(),
+    };
+
+    ext.and_then(emoji_from_ext).unwrap_or(DEFAULT_EMOJI)
+}
+
+/// Attempts to return an emoji given a file extension.
+fn emoji_from_ext(ext: &OsStr) -> Option<&'static str> {
+    EXT_EMOJI_MAP.get(ext).copied()
+}
+
+/// Default fallback emoji.
+pub const DEFAULT_EMOJI: &str = "📄";
+
+/// Emoji for special file-types. Keys are those returned by [`super::file_type_key`].
+static FILE_TYPE_EMOJI_MAP: Lazy<HashMap<&str, &str>> = Lazy::new(|| {
+    hash!(
+        "dir"          => "📁",
+        "symlink"      => "🔗",
+        "fifo"         => "🚰",
+        "socket"       => "🔌",
+        "char_device"  => "📟",
+        "block_device" => "💽"
+    )
+});
+
+/// Emoji for special file names.
+static FILE_NAME_EMOJI_MAP: Lazy<HashMap<OsString, &str>> = Lazy::new(|| {
+    hash!(
+        OsString::from(".gitignore")         => "🙈",
+        OsString::from("Cargo.lock")         => "🦀",
+        OsString::from("Cargo.toml")         => "🦀",
+        OsString::from("docker-compose.yml") => "🐳",
+        OsString::from("Dockerfile")         => "🐳",
+        OsString::from("LICENSE")            => "📜",
+        OsString::from("license")            => "📜",
+        OsString::from("Makefile")           => "🔨"
+    )
+});
+
+/// Emoji for various file extensions.
+static EXT_EMOJI_MAP: Lazy<HashMap<OsString, &str>> = Lazy::new(|| {
+    hash!(
+        OsString::from("7z")       => "📦",
+        OsString::from("avif")     => "📷",
+        OsString::from("bash")     => "🐚",
+        OsString::from("bmp")      => "📷",
+        OsString::from("c")        => "🔧",
+        OsString::from("cfg")      => "🔧",
+        OsString::from("conf")     => "🔧",
+        OsString::from("cpp")      => "🔧",
+        OsString::from("css")      => "🎨",
+        OsString::from("csv")      => "📊",
+        OsString::from("dart")     => "🎯",
+        OsString::from("db")       => "💾",
+        OsString::from("deb")      => "📦",
+        OsString::from("fish")     => "🐚",
+        OsString::from("flac")     => "🎵",
+        OsString::from("gif")      => "📷",
+        OsString::from("go")       => "🐹",
+        OsString::from("gz")       => "📦",
+        OsString::from("h")        => "🔧",
+        OsString::from("heic")     => "📷",
+        OsString::from("hpp")      => "🔧",
+        OsString::from("html")     => "🌐",
+        OsString::from("ico")      => "📷",
+        OsString::from("ini")      => "🔧",
+        OsString::from("java")     => "☕",
+        OsString::from("jpeg")     => "📷",
+        OsString::from("jpg")      => "📷",
+        OsString::from("js")       => "🟨",
+        OsString::from("json")     => "🔧",
+        OsString::from("jsx")      => "🟨",
+        OsString::from("lock")     => "🔒",
+        OsString::from("log")      => "🪵",
+        OsString::from("lua")      => "🌙",
+        OsString::from("md")       => "📝",
+        OsString::from("markdown") => "📝",
+        OsString::from("mjs")      => "🟨",
+        OsString::from("mkv")      => "🎬",
+        OsString::from("mp3")      => "🎵",
+        OsString::from("mp4")      => "🎬",
+        OsString::from("opus")     => "🎵",
+        OsString::from("pdf")      => "📕",
+        OsString::from("php")      => "🐘",
+        OsString::from("png")      => "📷",
+        OsString::from("py")       => "🐍",
+        OsString::from("rb")       => "💎",
+        OsString::from("rs")       => "🦀",
+        OsString::from("sass")     => "🎨",
+        OsString::from("scss")     => "🎨",
+        OsString::from("sh")       => "🐚",
+        OsString::from("sql")      => "💾",
+        OsString::from("sqlite")   => "💾",
+        OsString::from("svg")      => "📷",
+        OsString::from("swift")    => "🐦",
+        OsString::from("tar")      => "📦",
+        OsString::from("toml")     => "🔧",
+        OsString::from("ts")       => "🟦",
+        OsString::from("tsv")      => "📊",
+        OsString::from("tsx")      => "🟦",
+        OsString::from("txt")      => "📃",
+        OsString::from("wav")      => "🎵",
+        OsString::from("webp")     => "📷",
+        OsString::from("xls")      => "📊",
+        OsString::from("yaml")     => "🔧",
+        OsString::from("yml")      => "🔧",
+        OsString::from("zip")      => "📦",
+        OsString::from("zsh")      => "🐚",
+        OsString::from("zst")      => "📦"
+    )
+});
+
+#[test]
+fn test_emoji_from_ext() {
+    assert_eq!(emoji_from_ext(OsStr::new("rs")), Some("🦀"));
+    assert_eq!(emoji_from_ext(OsStr::new("py")), Some("🐍"));
+    assert_eq!(emoji_from_ext(OsStr::new("erd")), None);
+}
@@ -1,12 +1,13 @@
-use ansi_term::{ANSIGenericString, Style};
+use ansi_term::Style;
 use ignore::DirEntry;
 use std::{borrow::Cow, path::Path};
 
 /// Computes a plain, colorless icon with given parameters.
 ///
 /// The precedent from highest to lowest in terms of which parameters determine the icon used
-/// is as followed: file-type, file-extension, and then file-name. If an icon cannot be
-/// computed the fall-back default icon is used.
+/// is as followed: file-type, file-name, and then file-extension. Matching the complete file-name
+/// first allows multi-dot names such as `.eslintrc.json` to have an icon distinct from that of
+/// their extension. If an icon cannot be computed the fall-back default icon is used.
 ///
 /// If a directory entry is a link and the link target is provided, the link target will be
 /// used to determine the icon.
@@ -20,6 +21,12 @@ pub fn compute(entry: &DirEntry, link_target: Option<&Path>) -> Cow<'static, str
         return i;
     }
 
+    let icon = super::icon_from_file_name(entry.file_name()).map(Cow::from);
+
+    if let Some(i) = icon {
+        return i;
+    }
+
     let ext = match link_target {
         Some(target) if entry.path_is_symlink() => target.extension(),
         _ => entry.path().extension(),
@@ -33,12 +40,6 @@ pub fn compute(entry: &DirEntry, link_target: Option<&Path>) -> Cow<'static, str
         return i;
     }
 
-    let icon = super::icon_from_file_name(entry.file_name()).map(Cow::from);
-
-    if let Some(i) = icon {
-        return i;
-    }
-
     Cow::from(super::get_default_icon().1)
 }
 
@@ -48,27 +49,26 @@ pub fn compute_with_color(
     link_target: Option<&Path>,
     style: Option<Style>,
 ) -> Cow<'static, str> {
-    let icon = entry
-        .file_type()
-        .and_then(super::icon_from_file_type)
-        .map(Cow::from);
+    let icon = entry.file_type().and_then(super::icon_from_file_type);
 
     let paint_icon = |icon| match style {
         Some(Style {
             foreground: Some(fg),
             ..
-        }) => {
-            let ansi_string: ANSIGenericString<str> = fg.paint(icon);
-            let styled_icon = ansi_string.to_string();
-            Cow::from(styled_icon)
-        },
-        _ => icon,
+        }) => Cow::from(super::paint(icon, fg)),
+        _ => Cow::from(icon),
     };
 
     if let Some(icon) = icon {
         return paint_icon(icon);
     }
 
+    let icon = super::icon_from_file_name(entry.file_name()).map(paint_icon);
+
+    if let Some(i) = icon {
+        return i;
+    }
+
     let ext = match link_target {
         Some(target) if entry.path_is_symlink() => target.extension(),
         _ => entry.path().extension(),
@@ -82,14 +82,18 @@ pub fn compute_with_color(
         return i;
     }
 
-    let icon = super::icon_from_file_name(entry.file_name())
-        .map(Cow::from)
-        .map(paint_icon);
-
-    if let Some(i) = icon {
-        return i;
-    }
-
     let (code, icon) = super::get_default_icon();
     Cow::from(super::col(code, icon))
 }
+
+/// The fall-back default icon, colored unless `no_color`. Used for entries that don't correspond
+/// to a single file such as the placeholder for entries truncated by '--max-entries'.
+pub fn default_icon(no_color: bool) -> Cow<'static, str> {
+    let (code, icon) = super::get_default_icon();
+
+    if no_color {
+        Cow::from(icon)
+    } else {
+        Cow::from(super::col(code, icon))
+    }
+}
@@ -5,46 +5,157 @@ use std::{
     collections::HashMap,
     ffi::{OsStr, OsString},
     fs::FileType,
+    sync::{OnceLock, RwLock},
 };
 
 /// Concerned with computing icons given filesystem parameters.
 pub mod fs;
 
+/// Emoji alternatives to the Nerd Font icons for fonts without the necessary glyphs.
+pub mod emoji;
+
+/// A user-defined icon for a file extension set by the `[icons]` table of `.erdtree.toml`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Override {
+    pub icon: char,
+
+    /// 8-bit color code of the icon. If absent the color of the icon being overridden is used.
+    pub color: Option<u8>,
+}
+
+/// Registers user-defined icons which take precedence over [`EXT_ICON_MAP`]. This should be
+/// called at most once before any icons are computed; subsequent calls are ignored.
+pub fn init_overrides(overrides: &HashMap<String, Override>) {
+    let overrides = overrides
+        .iter()
+        .map(|(ext, Override { icon, color })| {
+            let ext = OsString::from(ext);
+
+            let color = color.unwrap_or_else(|| {
+                EXT_ICON_MAP
+                    .get(&ext)
+                    .map_or_else(|| get_default_icon().0, |(code, _)| *code)
+            });
+
+            let icon: &'static str = Box::leak(icon.to_string().into_boxed_str());
+
+            (ext, (color, icon))
+        })
+        .collect();
+
+    let _ = EXT_ICON_OVERRIDES.set(overrides);
+}
+
 /// Attempts to return an icon given a file extension along with its default color code 8-bit
-/// value.
+/// value. User-defined icons registered with [`init_overrides`] are consulted first.
 fn icon_from_ext(ext: &OsStr) -> Option<(u8, &'static str)> {
-    EXT_ICON_MAP.get(ext).map(|(code, icon)| (*code, *icon))
+    EXT_ICON_OVERRIDES
+        .get()
+        .and_then(|overrides| overrides.get(ext))
+        .or_else(|| EXT_ICON_MAP.get(ext))
+        .map(|(code, icon)| (*code, *icon))
 }
 
 /// Attempts to return an icon based on file type.
 fn icon_from_file_type(ft: FileType) -> Option<&'static str> {
+    file_type_key(ft).and_then(|key| FILE_TYPE_ICON_MAP.get(key).copied())
+}
+
+/// The key of file types that have a dedicated icon, e.g. `"dir"`, in the maps of file-type icons.
+fn file_type_key(ft: FileType) -> Option<&'static str> {
     if ft.is_dir() {
-        return FILE_TYPE_ICON_MAP.get("dir").copied();
+        return Some("dir");
     } else if ft.is_symlink() {
-        return FILE_TYPE_ICON_MAP.get("symlink").copied();
+        return Some("symlink");
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::FileTypeExt;
+
+        if ft.is_fifo() {
+            return Some("fifo");
+        } else if ft.is_socket() {
+            return Some("socket");
+        } else if ft.is_char_device() {
+            return Some("char_device");
+        } else if ft.is_block_device() {
+            return Some("block_device");
+        }
     }
 
     None
 }
 
-/// Attempts to get the icon associated with the special file kind.
+/// Attempts to get the icon associated with the special file kind by matching the complete file
+/// name, e.g. `docker-compose.override.yml` or `.eslintrc.json`.
 fn icon_from_file_name(name: &OsStr) -> Option<&'static str> {
     FILE_NAME_ICON_MAP.get(name).copied()
 }
 
 /// Returns the default fallback icon.
-fn get_default_icon<'a>() -> (u8, &'a str) {
+fn get_default_icon() -> (u8, &'static str) {
     *DEFAULT_ICON
 }
 
 /// Convenience method to paint fixed colors.
-fn col(num: u8, code: &str) -> String {
-    Color::Fixed(num).paint(code).to_string()
+fn col(num: u8, code: &'static str) -> &'static str {
+    paint(code, Color::Fixed(num))
 }
 
+/// Paints `icon` with `color`. Painted icons are cached so that the many entries sharing an icon
+/// and color, e.g. files of the same extension, don't each allocate a new string. The number of
+/// distinct icon and color combinations is small so cached icons live for the rest of the program.
+fn paint(icon: &'static str, color: Color) -> &'static str {
+    let key = (icon, color_key(color));
+
+    let cached = PAINTED_ICON_CACHE
+        .read()
+        .ok()
+        .and_then(|cache| cache.get(&key).copied());
+
+    if let Some(painted) = cached {
+        return painted;
+    }
+
+    let painted: &'static str = Box::leak(color.paint(icon).to_string().into_boxed_str());
+
+    PAINTED_ICON_CACHE
+        .write()
+        .map_or(painted, |mut cache| *cache.entry(key).or_insert(painted))
+}
+
+/// [Color] doesn't implement [Hash] so this encodes it as an integer to be used as a cache key.
+fn color_key(color: Color) -> u32 {
+    match color {
+        Color::Black => 0,
+        Color::Red => 1,
+        Color::Green => 2,
+        Color::Yellow => 3,
+        Color::Blue => 4,
+        Color::Purple => 5,
+        Color::Cyan => 6,
+        Color::White => 7,
+        Color::Fixed(num) => (1 << 8) | u32::from(num),
+        Color::RGB(r, g, b) => {
+            (1 << 24) | (u32::from(r) << 16) | (u32::from(g) << 8) | u32::from(b)
+        },
+    }
+}
+
+/// Icon shared by archive and package formats.
+const ARCHIVE_ICON: (u8, &str) = (172, "\u{f410}");
+
 /// Default fallback icon.
 static DEFAULT_ICON: Lazy<(u8, &str)> = Lazy::new(|| (66, "\u{f15b}"));
 
+/// User-defined icons of file extensions. See [init_overrides].
+static EXT_ICON_OVERRIDES: OnceLock<HashMap<OsString, (u8, &str)>> = OnceLock::new();
+
+/// Cache of painted icons keyed by icon and color. See [paint].
+static PAINTED_ICON_CACHE: Lazy<RwLock<HashMap<(&str, u32), &str>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
 /// Lazily evaluated static hash-map of special file-types and their corresponding styled icons.
 /// These icons will take on the color properties of their associated file which is based on
 /// `LS_COLORS`.
@@ -52,8 +163,12 @@ static DEFAULT_ICON: Lazy<(u8, &str)> = Lazy::new(|| (66, "\u{f15b}"));
 /// Dev icons sourced from [`exa`](https://github.com/ogham/exa/blob/master/src/output/icons.rs)
 static FILE_TYPE_ICON_MAP: Lazy<HashMap<&str, &str>> = Lazy::new(|| {
     hash!(
-        "dir"     => "\u{f4d4}", // 
-        "symlink" => "\u{f481}"  // 
+        "dir"          => "\u{f4d4}", // 
+        "symlink"      => "\u{f481}", // 
+        "fifo"         => "\u{f0ec}", // 
+        "socket"       => "\u{f1e6}", // 
+        "char_device"  => "\u{f11c}", // 
+        "block_device" => "\u{f0a0}"  // 
     )
 });
 
@@ -67,23 +182,36 @@ static FILE_NAME_ICON_MAP: Lazy<HashMap<OsString, &str>> = Lazy::new(|| {
         OsString::from(".atom")              => "\u{e764}", // 
         OsString::from(".bashprofile")       => "\u{e615}", // 
         OsString::from(".bashrc")            => "\u{f489}", // 
+        OsString::from(".dockerignore")      => "\u{f308}", // 
+        OsString::from(".editorconfig")      => "\u{e615}", // 
+        OsString::from(".env")               => "\u{f462}", // 
+        OsString::from(".eslintignore")      => "\u{e655}", // 
+        OsString::from(".eslintrc")          => "\u{e655}", // 
+        OsString::from(".eslintrc.js")       => "\u{e655}", // 
+        OsString::from(".eslintrc.json")     => "\u{e655}", // 
         OsString::from(".git")               => "\u{f1d3}", // 
         OsString::from(".gitattributes")     => "\u{f1d3}", // 
         OsString::from(".gitconfig")         => "\u{f1d3}", // 
         OsString::from(".github")            => "\u{f408}", // 
         OsString::from(".gitignore")         => "\u{f1d3}", // 
+        OsString::from(".gitlab-ci.yml")     => "\u{f296}", // 
         OsString::from(".gitmodules")        => "\u{f1d3}", // 
+        OsString::from(".npmrc")             => "\u{e71e}", // 
         OsString::from(".rvm")               => "\u{e21e}", // 
         OsString::from(".vimrc")             => "\u{e62b}", // 
         OsString::from(".vscode")            => "\u{e70c}", // 
         OsString::from(".zshrc")             => "\u{f489}", // 
         OsString::from("Cargo.lock")         => "\u{e7a8}", // 
+        OsString::from("Cargo.toml")         => "\u{e7a8}", // 
         OsString::from("bin")                => "\u{e5fc}", // 
         OsString::from("config")             => "\u{e5fc}", // 
         OsString::from("docker-compose.yml") => "\u{f308}", // 
+        OsString::from("docker-compose.override.yml") => "\u{f308}", // 
+        OsString::from("docker-compose.yaml") => "\u{f308}", // 
         OsString::from("Dockerfile")         => "\u{f308}", // 
         OsString::from(".DS_Store")          => "\u{f179}", // 
         OsString::from("gitignore_global")   => "\u{f1d3}", // 
+        OsString::from("Gemfile")            => "\u{e791}", // 
         OsString::from("go.mod")             => "\u{e626}", // 
         OsString::from("go.sum")             => "\u{e626}", // 
         OsString::from("gradle")             => "\u{e256}", // 
@@ -103,9 +231,16 @@ static FILE_NAME_ICON_MAP: Lazy<HashMap<OsString, &str>> = Lazy::new(|| {
         OsString::from("localized")          => "\u{f179}", // 
         OsString::from("Makefile")           => "\u{f489}", // 
         OsString::from("node_modules")       => "\u{e718}", // 
+        OsString::from("package.json")       => "\u{e71e}", // 
+        OsString::from("package-lock.json")  => "\u{e71e}", // 
         OsString::from("npmignore")          => "\u{e71e}", // 
         OsString::from("PKGBUILD")           => "\u{f303}", // 
+        OsString::from("Rakefile")           => "\u{e791}", // 
         OsString::from("rubydoc")            => "\u{e73b}", // 
+        OsString::from("tsconfig.json")      => "\u{e628}", // 
+        OsString::from("vite.config.js")     => "\u{f0e7}", // 
+        OsString::from("vite.config.ts")     => "\u{f0e7}", // 
+        OsString::from("webpack.config.js")  => "\u{f072b}", // 󰜫
         OsString::from("yarn.lock")          => "\u{e718}"  // 
     )
 });
@@ -117,8 +252,10 @@ static FILE_NAME_ICON_MAP: Lazy<HashMap<OsString, &str>> = Lazy::new(|| {
 /// Dev icons and their color palettes sourced from [`nvim-web-devicons`](https://github.com/nvim-tree/nvim-web-devicons/blob/master/lua/nvim-web-devicons.lua).
 static EXT_ICON_MAP: Lazy<HashMap<OsString, (u8, &str)>> = Lazy::new(|| {
     hash!(
+        OsString::from("7z")            => ARCHIVE_ICON,        // 
         OsString::from("ai")            => (185, "\u{e7b4}"),   // 
         OsString::from("awk")           => (59, "\u{e795}"),    // 
+        OsString::from("avif")          => (140, "\u{e60d}"),   // 
         OsString::from("bash")          => (113, "\u{e795}"),   // 
         OsString::from("bat")           => (154, "\u{e615}"),   // 
         OsString::from("bmp")           => (140, "\u{e60d}"),   // 
@@ -152,6 +289,7 @@ static EXT_ICON_MAP: Lazy<HashMap<OsString, (u8, &str)>> = Lazy::new(|| {
         OsString::from("d")             => (64, "\u{e7af}"),    // 
         OsString::from("desktop")       => (60, "\u{f108}"),    // 
         OsString::from("diff")          => (59, "\u{e728}"),    // 
+        OsString::from("deb")           => ARCHIVE_ICON,        // 
         OsString::from("doc")           => (25, "\u{f022c}"),   // 󰈬
         OsString::from("drl")           => (217, "\u{e28c}"),   // 
         OsString::from("dropbox")       => (27, "\u{e707}"),    // 
@@ -160,6 +298,7 @@ static EXT_ICON_MAP: Lazy<HashMap<OsString, (u8, &str)>> = Lazy::new(|| {
         OsString::from("eex")           => (140, "\u{e62d}"),   // 
         OsString::from("ejs")           => (185, "\u{e60e}"),   // 
         OsString::from("elm")           => (67, "\u{e62c}"),    // 
+        OsString::from("env")           => (226, "\u{f462}"),   // 
         OsString::from("epp")           => (255, "\u{e631}"),   // 
         OsString::from("erb")           => (52, "\u{e60e}"),    // 
         OsString::from("erl")           => (132, "\u{e7b1}"),   // 
@@ -167,6 +306,7 @@ static EXT_ICON_MAP: Lazy<HashMap<OsString, (u8, &str)>> = Lazy::new(|| {
         OsString::from("exs")           => (140, "\u{e62d}"),   // 
         OsString::from("f#")            => (67, "\u{e7a7}"),    // 
         OsString::from("fish")          => (59, "\u{e795}"),    // 
+        OsString::from("flac")          => (66, "\u{f001}"),    // 
         OsString::from("fnl")           => (230, "\u{1f31c}"),  // 🌜
         OsString::from("fs")            => (67, "\u{e7a7}"),    // 
         OsString::from("fsi")           => (67, "\u{e7a7}"),    // 
@@ -182,8 +322,10 @@ static EXT_ICON_MAP: Lazy<HashMap<OsString, (u8, &str)>> = Lazy::new(|| {
         OsString::from("godot")         => (66, "\u{e7a3}"),    // 
         OsString::from("gql")           => (199, "\u{f20e}"),   // 
         OsString::from("graphql")       => (199, "\u{f20e}"),   // 
+        OsString::from("gz")            => ARCHIVE_ICON,        // 
         OsString::from("haml")          => (188, "\u{e60e}"),   // 
         OsString::from("hbs")           => (208, "\u{e60f}"),   // 
+        OsString::from("heic")          => (140, "\u{e60d}"),   // 
         OsString::from("h")             => (140, "\u{f0fd}"),   // 
         OsString::from("heex")          => (140, "\u{e62d}"),   // 
         OsString::from("hh")            => (140, "\u{f0fd}"),   // 
@@ -203,6 +345,7 @@ static EXT_ICON_MAP: Lazy<HashMap<OsString, (u8, &str)>> = Lazy::new(|| {
         OsString::from("js")            => (185, "\u{e60c}"),   // 
         OsString::from("json5")         => (185, "\u{f0626}"),  // 󰘦
         OsString::from("json")          => (185, "\u{e60b}"),   // 
+        OsString::from("jsonc")         => (185, "\u{e60b}"),   // 
         OsString::from("jsx")           => (67, "\u{e625}"),    // 
         OsString::from("ksh")           => (59, "\u{e795}"),    // 
         OsString::from("kt")            => (99, "\u{e634}"),    // 
@@ -225,6 +368,7 @@ static EXT_ICON_MAP: Lazy<HashMap<OsString, (u8, &str)>> = Lazy::new(|| {
         OsString::from("mint")          => (108, "\u{f032a}"),  // 󰌪
         OsString::from("mjs")           => (221, "\u{e60c}"),   // 
         OsString::from("mk")            => (66, "\u{e779}"),    // 
+        OsString::from("mkv")           => (241, "\u{f03d}"),   // 
         OsString::from("ml")            => (173, "\u{3bb}"),    // λ
         OsString::from("mli")           => (173, "\u{3bb}"),    // λ
         OsString::from("mo")            => (99, "\u{221e}"),    // ∞
@@ -284,6 +428,7 @@ static EXT_ICON_MAP: Lazy<HashMap<OsString, (u8, &str)>> = Lazy::new(|| {
         OsString::from("svh")           => (29, "\u{f035b}"),   // 󰍛
         OsString::from("swift")         => (173, "\u{e755}"),   // 
         OsString::from("tbc")           => (67, "\u{f06d3}"),   // 󰛓
+        OsString::from("tar")           => ARCHIVE_ICON,        // 
         OsString::from("t")             => (67, "\u{e769}"),    // 
         OsString::from("tcl")           => (67, "\u{f06d3}"),   // 󰛓
         OsString::from("terminal")      => (71, "\u{f489}"),    // 
@@ -295,6 +440,7 @@ static EXT_ICON_MAP: Lazy<HashMap<OsString, (u8, &str)>> = Lazy::new(|| {
         OsString::from("tres")          => (185, "\u{e706}"),   // 
         OsString::from("ts")            => (67, "\u{e628}"),    // 
         OsString::from("tscn")          => (140, "\u{f0381}"),  // 󰎁
+        OsString::from("tsv")           => (113, "\u{f0219}"),  // 󰈙
         OsString::from("tsx")           => (67, "\u{e7ba}"),    // 
         OsString::from("twig")          => (107, "\u{e61c}"),   // 
         OsString::from("txt")           => (113, "\u{f0219}"),  // 󰈙
@@ -305,6 +451,7 @@ static EXT_ICON_MAP: Lazy<HashMap<OsString, (u8, &str)>> = Lazy::new(|| {
         OsString::from("vhdl")          => (29, "\u{f035b}"),   // 󰍛
         OsString::from("vim")           => (29, "\u{e62b}"),    // 
         OsString::from("vue")           => (107, "\u{f0844}"),  // 󰡄
+        OsString::from("wav")           => (66, "\u{f001}"),    // 
         OsString::from("wasm")          => (99, "\u{e6a1}"),    // 
         OsString::from("webmanifest")   => (221, "\u{e60b}"),   // 
         OsString::from("webpack")       => (67, "\u{f072b}"),   // 󰜫
@@ -316,6 +463,82 @@ static EXT_ICON_MAP: Lazy<HashMap<OsString, (u8, &str)>> = Lazy::new(|| {
         OsString::from("yaml")          => (66, "\u{e615}"),    // 
         OsString::from("yml")           => (66, "\u{e615}"),    // 
         OsString::from("zig")           => (208, "\u{f0e7}"),   // 
+        OsString::from("zst")           => ARCHIVE_ICON,        // 
         OsString::from("zsh")           => (113, "\u{e795}")    // 
     )
 });
+
+#[test]
+fn test_paint_cache() {
+    let (code, icon) = get_default_icon();
+
+    let cached = col(code, icon);
+    let fresh = Color::Fixed(code).paint(icon).to_string();
+
+    assert_eq!(cached, fresh);
+    assert!(
+        std::ptr::eq(cached, col(code, icon)),
+        "Expected cached icon to be reused"
+    );
+
+    let rust_icon = icon_from_ext(OsStr::new("rs")).map(|(_, i)| i).unwrap();
+    let rgb = Color::RGB(0xde, 0xa5, 0x84);
+
+    assert_eq!(paint(rust_icon, rgb), rgb.paint(rust_icon).to_string());
+    assert_eq!(
+        paint(rust_icon, Color::Red),
+        Color::Red.paint(rust_icon).to_string()
+    );
+    assert_ne!(paint(rust_icon, rgb), paint(rust_icon, Color::Red));
+}
+
+#[test]
+fn test_icon_overrides() {
+    let overrides = HashMap::from([
+        (
+            "rs".to_owned(),
+            Override {
+                icon: '\u{f0219}',
+                color: None,
+            },
+        ),
+        (
+            "erd".to_owned(),
+            Override {
+                icon: '\u{e7a8}',
+                color: Some(208),
+            },
+        ),
+    ]);
+
+    init_overrides(&overrides);
+
+    assert_eq!(
+        icon_from_ext(OsStr::new("rs")),
+        Some((180, "\u{f0219}")),
+        "Overridden icon should keep the original color if none is provided"
+    );
+    assert_eq!(icon_from_ext(OsStr::new("erd")), Some((208, "\u{e7a8}")));
+    assert_eq!(icon_from_ext(OsStr::new("py")), Some((61, "\u{e606}")));
+}
+
+#[test]
+fn test_added_ext_icons() {
+    let exts = [
+        "jsonc", "env", "tsv", "avif", "heic", "mkv", "flac", "wav", "7z", "tar", "gz", "zst",
+        "deb",
+    ];
+
+    for ext in exts {
+        assert!(
+            icon_from_ext(OsStr::new(ext)).is_some(),
+            "Expected an icon for '.{ext}'"
+        );
+    }
+
+    let archive = icon_from_ext(OsStr::new("tar"));
+
+    for ext in ["7z", "gz", "zst", "deb"] {
+        assert_eq!(icon_from_ext(OsStr::new(ext)), archive);
+    }
+}
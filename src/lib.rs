@@ -0,0 +1,81 @@
+//! erdtree (erd) is a cross-platform, multi-threaded, and general purpose filesystem and disk
+//! usage utility. Besides the `erd` binary, the traversal is exposed as a library so that a
+//! [`Tree`] can be built and inspected programmatically:
+//!
+//! ```no_run
+//! use clap::Parser;
+//! use erdtree::Context;
+//!
+//! let ctx = Context::try_parse_from(["erd", "--disk-usage", "logical", "src"]).unwrap();
+//! let tree = erdtree::build_tree(&ctx).unwrap();
+//!
+//! for child in tree.children(tree.root_id()) {
+//!     println!("{}", child.path().display());
+//! }
+//! ```
+#![cfg_attr(windows, feature(windows_by_handle))]
+#![warn(
+    clippy::all,
+    clippy::cargo,
+    clippy::complexity,
+    clippy::correctness,
+    clippy::nursery,
+    clippy::pedantic,
+    clippy::perf,
+    clippy::style,
+    clippy::suspicious
+)]
+#![allow(clippy::cast_precision_loss, clippy::struct_excessive_bools)]
+
+pub use context::Context;
+pub use tree::{error::Error, node::Node, Tree};
+
+// The modules below are also compiled into the `erd` binary, which declares them itself. Parts of
+// them are only reachable from its rendering code, hence the `dead_code` allowances here.
+
+/// CLI rules and definitions as well as context to be injected throughout the entire program.
+mod context;
+
+/// Operations relevant to the computation and presentation of disk usage.
+mod disk_usage;
+
+/// Filesystem operations.
+#[allow(dead_code)]
+mod fs;
+
+/// All things related to icons on how to map certain files to the appropriate icons.
+#[allow(dead_code)]
+mod icons;
+
+/// Concerned with displaying a progress indicator when stdout is a tty.
+#[allow(dead_code)]
+mod progress;
+
+/// Global used throughout the program to paint the output.
+#[allow(dead_code)]
+mod styles;
+
+/// Houses the primary data structures that are used to virtualize the filesystem, containing also
+/// information on how the tree output should be ultimately rendered.
+#[allow(dead_code)]
+mod tree;
+
+/// Utilities relating to interacting with tty properties.
+#[allow(dead_code)]
+mod tty;
+
+/// Common utilities across all modules.
+mod utils;
+
+/// Traverses the directory given by `ctx` in parallel and assembles the resulting [`Tree`] without
+/// rendering it or writing anything to stdout. This is the same traversal that the `erd` binary
+/// performs before rendering its output.
+///
+/// # Errors
+///
+/// Returns an error if the root directory can't be read, the [`Context`] is invalid for traversal,
+/// e.g. due to a malformed pattern, or if a search via '--pattern' yields no matches.
+pub fn build_tree(ctx: &Context) -> Result<Tree, Error> {
+    let mut column_properties = context::column::Properties::from(ctx);
+    Tree::build(ctx, &mut column_properties, None)
+}
@@ -15,9 +15,19 @@
 use clap::CommandFactory;
 use context::{layout, Context};
 use progress::{Indicator, IndicatorHandle, Message};
-use render::{Engine, Flat, FlatInverted, Inverted, Regular};
-use std::{error::Error, io::stdout, process::ExitCode};
-use tree::Tree;
+use render::{
+    ByExtension, Csv, Engine, Flat, FlatInverted, Folded, Indent, Inverted, Markdown, Print0,
+    Regular, Top,
+};
+use std::{
+    error::Error,
+    fmt::Display,
+    fs::File,
+    io::{self, stdout, BufWriter, Write},
+    process::ExitCode,
+    time::Instant,
+};
+use tree::{skipped, Tree};
 
 /// Operations to wrangle ANSI escaped strings.
 mod ansi;
@@ -53,10 +63,25 @@ mod tty;
 /// Common utilities across all modules.
 mod utils;
 
+/// Exit code used when a search via '--pattern' yields no matches, akin to `grep`.
+const NO_MATCHES_EXIT_CODE: u8 = 1;
+
+/// Exit code used when an error occurs.
+const ERROR_EXIT_CODE: u8 = 2;
+
 fn main() -> ExitCode {
     if let Err(e) = run() {
         eprintln!("{e}");
-        return ExitCode::FAILURE;
+
+        let no_matches = e
+            .downcast_ref::<tree::error::Error>()
+            .is_some_and(|err| matches!(err, tree::error::Error::NoMatches));
+
+        if no_matches {
+            return ExitCode::from(NO_MATCHES_EXIT_CODE);
+        }
+
+        return ExitCode::from(ERROR_EXIT_CODE);
     }
 
     ExitCode::SUCCESS
@@ -70,7 +95,11 @@ fn run() -> Result<(), Box<dyn Error>> {
         return Ok(());
     }
 
-    styles::init(ctx.no_color());
+    styles::init(ctx.no_color(), &ctx.glyphs());
+
+    if let Some(ref overrides) = ctx.icon_overrides {
+        icons::init_overrides(overrides);
+    }
 
     let indicator = Indicator::maybe_init(&ctx);
 
@@ -84,20 +113,98 @@ fn run() -> Result<(), Box<dyn Error>> {
         }
     };
 
-    macro_rules! compute_output {
+    let trailing_newline = !ctx.no_trailing_newline && !ctx.print0;
+    let output_path = ctx.output.clone();
+    let stream = ctx.depth_first;
+    let report_timing = ctx.timing;
+    let mut timing = tree.timing();
+    let report_stats = ctx.stats;
+    let stats = tree.stats();
+    let skipped = (!ctx.quiet && !tree.skipped().is_empty())
+        .then(|| skipped::Report(tree.skipped()).to_string());
+    let render_start = Instant::now();
+
+    macro_rules! engine {
         ($t:ty) => {{
-            let render = Engine::<$t>::new(tree, ctx);
-            format!("{render}")
+            Box::new(Engine::<$t>::new(tree, ctx)) as Box<dyn Display>
         }};
     }
 
-    let output = match ctx.layout {
-        layout::Type::Flat => compute_output!(Flat),
-        layout::Type::Iflat => compute_output!(FlatInverted),
-        layout::Type::Inverted => compute_output!(Inverted),
-        layout::Type::Regular => compute_output!(Regular),
+    let render = if ctx.print0 {
+        engine!(Print0)
+    } else if ctx.markdown {
+        engine!(Markdown)
+    } else if ctx.csv {
+        engine!(Csv)
+    } else if ctx.folded {
+        engine!(Folded)
+    } else if ctx.by_extension {
+        engine!(ByExtension)
+    } else if ctx.indent {
+        engine!(Indent)
+    } else if ctx.top.is_some() {
+        engine!(Top)
+    } else {
+        match ctx.layout {
+            layout::Type::Flat => engine!(Flat),
+            layout::Type::Iflat => engine!(FlatInverted),
+            layout::Type::Inverted => engine!(Inverted),
+            layout::Type::Regular => engine!(Regular),
+        }
+    };
+
+    let suppress_output = cfg!(debug_assertions) && std::env::var_os("ERDTREE_DEBUG").is_some();
+
+    let write_output = |output: &dyn Display| -> io::Result<()> {
+        if suppress_output {
+            return Ok(());
+        }
+
+        let mut writer: Box<dyn Write> = match output_path {
+            Some(ref path) => Box::new(BufWriter::new(File::create(path)?)),
+            None => Box::new(BufWriter::new(stdout().lock())),
+        };
+
+        if trailing_newline {
+            writeln!(writer, "{output}")?;
+        } else {
+            write!(writer, "{output}")?;
+        }
+
+        writer.flush()
     };
 
+    // When streaming, rows are written out as they are rendered so the output is never held in
+    // memory all at once; the progress indicator must therefore be cleared beforehand.
+    if stream {
+        finish_indicator(indicator)?;
+        write_output(&render)?;
+        timing.rendering = render_start.elapsed();
+    } else {
+        let output = render.to_string();
+        timing.rendering = render_start.elapsed();
+        finish_indicator(indicator)?;
+        write_output(&output)?;
+    }
+
+    if report_timing {
+        eprintln!("{timing}");
+    }
+
+    if report_stats {
+        eprintln!("{stats}");
+    }
+
+    if let Some(report) = skipped {
+        eprintln!("{report}");
+    }
+
+    Ok(())
+}
+
+/// Informs the progress indicator, if any, that output is ready to be written and waits for it to
+/// clean up after itself.
+fn finish_indicator(indicator: Option<IndicatorHandle>) -> Result<(), Box<dyn Error>> {
     if let Some(mut progress) = indicator {
         progress.mailbox().send(Message::RenderReady)?;
 
@@ -108,17 +215,5 @@ fn run() -> Result<(), Box<dyn Error>> {
             .transpose()?;
     }
 
-    #[cfg(debug_assertions)]
-    {
-        if std::env::var_os("ERDTREE_DEBUG").is_none() {
-            println!("{output}");
-        }
-    }
-
-    #[cfg(not(debug_assertions))]
-    {
-        println!("{output}");
-    }
-
     Ok(())
 }
@@ -1,13 +1,14 @@
 use crate::{
     context::Context,
     disk_usage::{
-        file_size::{byte, DiskUsage, FileSize},
+        file_size::{self, byte, DiskUsage, FileSize},
         units::{BinPrefix, PrefixKind, SiPrefix},
     },
     render::theme,
     styles,
-    tree::node::Node,
+    tree::{node::Node, sparkline::Sparkline},
 };
+use ansi_term::Style;
 use std::{
     ffi::OsStr,
     fmt::{self, Display},
@@ -15,7 +16,7 @@ use std::{
 };
 
 #[cfg(unix)]
-use chrono::{DateTime, Local};
+use chrono::{DateTime, Duration, Local};
 
 #[cfg(unix)]
 use crate::{
@@ -24,6 +25,25 @@ use crate::{
     styles::PLACEHOLDER,
 };
 
+/// Width of a rendered percentage e.g. `100.0%`.
+const PERCENT_WIDTH: usize = 6;
+
+/// Width of the bar rendered by '--bars' for an entry that makes up all of its parent directory.
+const BAR_WIDTH: usize = 10;
+
+/// Glyphs of increasing width used to draw the fractional end of a bar in eighths of a column.
+const PARTIAL_BARS: [char; 7] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+
+/// Glyph used to draw a fully filled column of a bar.
+const FULL_BAR: char = '█';
+
+/// ASCII equivalent of [`FULL_BAR`] used by '--ascii'.
+const ASCII_FULL_BAR: char = '#';
+
+/// Timestamps at least this old are shown as a date by '--time-format relative'.
+#[cfg(unix)]
+const RELATIVE_TIMESTAMP_CUTOFF_DAYS: i64 = 365;
+
 /// Constitutes a single cell in a given row of the output. The `kind` field denotes what type of
 /// data actually goes into the cell once rendered. Each `kind` which is of type [Kind] has its own
 /// rules for rendering. Cell's do not have to be of a consistent width.
@@ -40,13 +60,19 @@ pub enum Kind<'a> {
     },
     FilePath,
     FileSize,
+    Percent,
+    Bar,
     #[cfg(unix)]
-    Datetime,
+    Datetime {
+        stamp: time::Stamp,
+    },
     #[cfg(unix)]
     Ino,
     #[cfg(unix)]
     Nlink,
     #[cfg(unix)]
+    Xattrs,
+    #[cfg(unix)]
     Permissions,
     #[cfg(unix)]
     Owner,
@@ -72,13 +98,14 @@ impl<'a> Cell<'a> {
                 let pre = prefix.unwrap_or_default();
                 let name = theme::stylize_file_name(node);
 
-                if !ctx.icons {
-                    return write!(f, "{pre}{name}");
+                if ctx.icons {
+                    let icon = node.compute_icon(ctx.no_color(), ctx.icon_theme);
+                    write!(f, "{pre}{icon} {name}")?;
+                } else {
+                    write!(f, "{pre}{name}")?;
                 }
 
-                let icon = node.compute_icon(ctx.no_color());
-
-                write!(f, "{pre}{icon} {name}")
+                self.fmt_sparkline(f)
             },
 
             _ => unreachable!(),
@@ -91,28 +118,108 @@ impl<'a> Cell<'a> {
         let node = self.node;
         let ctx = self.ctx;
 
+        let elided_path = node
+            .is_elided()
+            .then(|| node.path().with_file_name(node.file_name()));
+
         let path = if node.depth() == 0 {
             let file_name = node.file_name();
             <OsStr as AsRef<Path>>::as_ref(file_name).display()
         } else {
-            node.path()
+            let node_path = elided_path.as_deref().unwrap_or_else(|| node.path());
+
+            node_path
                 .strip_prefix(ctx.dir_canonical())
-                .unwrap_or_else(|_| node.path())
+                .unwrap_or(node_path)
                 .display()
         };
 
-        let formatted_path = node.style().map_or_else(
-            || path.to_string(),
-            |style| format!("{}", style.paint(path.to_string())),
-        );
+        let path = path.to_string();
+        let path = ctx.strip_display_prefix(&path);
+
+        let formatted_path = node
+            .style()
+            .map_or_else(|| path.to_owned(), |style| format!("{}", style.paint(path)));
 
-        if !ctx.icons {
-            return write!(f, "{formatted_path}");
+        if ctx.icons {
+            let icon = node.compute_icon(ctx.no_color(), ctx.icon_theme);
+            write!(f, "{icon} {formatted_path}")?;
+        } else {
+            write!(f, "{formatted_path}")?;
         }
 
-        let icon = node.compute_icon(ctx.no_color());
+        self.fmt_sparkline(f)
+    }
 
-        write!(f, "{icon} {formatted_path}")
+    /// Rules on how to render the sparkline of a directory's children, if any, following its name.
+    #[inline]
+    fn fmt_sparkline(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(levels) = self.node.sparkline() else {
+            return Ok(());
+        };
+
+        let sparkline = Sparkline {
+            levels,
+            ascii: self.ctx.ascii,
+        };
+
+        write!(f, " {sparkline}")
+    }
+
+    /// Rules on how to render the disk usage of a file as a percentage of its parent directory's.
+    #[inline]
+    fn fmt_percent(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(percent) = self.node.percent() else {
+            let formatted_placeholder = format!("{:>PERCENT_WIDTH$}", styles::PLACEHOLDER);
+
+            if let Ok(style) = styles::get_placeholder_style() {
+                return write!(f, "{}", style.paint(formatted_placeholder));
+            }
+
+            return write!(f, "{formatted_placeholder}");
+        };
+
+        let width = PERCENT_WIDTH - 1;
+
+        write!(f, "{percent:>width$.1}%")
+    }
+
+    /// Rules on how to render a bar whose length is proportional to the disk usage of a file as a
+    /// percentage of its parent directory's. Bars are padded to [`BAR_WIDTH`] so that subsequent
+    /// columns remain aligned.
+    #[inline]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn fmt_bar(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let node = self.node;
+        let ctx = self.ctx;
+
+        let eighths = node.percent().map_or(0, |percent| {
+            let ratio = (percent / 100.0).clamp(0.0, 1.0);
+            (ratio * (BAR_WIDTH * 8) as f64).round() as usize
+        });
+
+        let (full, partial) = (eighths / 8, eighths % 8);
+
+        let mut bar = if ctx.ascii {
+            ASCII_FULL_BAR.to_string().repeat(full)
+        } else {
+            FULL_BAR.to_string().repeat(full)
+        };
+
+        if partial > 0 && !ctx.ascii {
+            bar.push(PARTIAL_BARS[partial - 1]);
+        }
+
+        let padded = format!("{bar:<BAR_WIDTH$}");
+
+        let style = node
+            .file_size()
+            .and_then(|file_size| Self::du_style(file_size, ctx));
+
+        match style {
+            Some(style) => write!(f, "{}", style.paint(padded)),
+            None => write!(f, "{padded}"),
+        }
     }
 
     /// Rules on how to render the file size.
@@ -122,7 +229,7 @@ impl<'a> Cell<'a> {
         let ctx = self.ctx;
 
         let Some(file_size) = node.file_size() else {
-            return Self::fmt_size_placeholder(f, ctx)
+            return Self::fmt_size_placeholder(f, ctx);
         };
 
         match file_size {
@@ -158,6 +265,36 @@ impl<'a> Cell<'a> {
         write!(f, "{formatted_nlink}")
     }
 
+    /// Rules on how to format the number of extended attributes for rendering. Files without any
+    /// get the placeholder.
+    #[cfg(unix)]
+    #[inline]
+    fn fmt_xattrs(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let node = self.node;
+        let ctx = self.ctx;
+
+        let max_width = ctx.max_xattr_width.max(PLACEHOLDER.len());
+
+        let Some(count) = node.xattr_count() else {
+            let out = format!("{PLACEHOLDER:>max_width$}");
+
+            return match styles::get_placeholder_style() {
+                Ok(style) => write!(f, "{}", style.paint(out)),
+                Err(_) => write!(f, "{out}"),
+            };
+        };
+
+        let out = format!("{count:>max_width$}");
+
+        let formatted_count = if let Ok(style) = styles::get_xattr_style() {
+            style.paint(out).to_string()
+        } else {
+            out
+        };
+
+        write!(f, "{formatted_count}")
+    }
+
     /// Rules on how to format ino for rendering.
     #[cfg(unix)]
     #[inline]
@@ -216,20 +353,28 @@ impl<'a> Cell<'a> {
     /// Rules on how to format datetime for rendering.
     #[cfg(unix)]
     #[inline]
-    fn fmt_datetime(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fn fmt_datetime(&self, f: &mut fmt::Formatter<'_>, stamp: time::Stamp) -> fmt::Result {
         let node = self.node;
-        let ctx = self.ctx;
 
-        let datetime = match ctx.time() {
+        let datetime = match stamp {
             time::Stamp::Create => node.created(),
             time::Stamp::Access => node.accessed(),
             time::Stamp::Mod => node.modified(),
         };
 
-        let out = datetime.map(DateTime::<Local>::from).map_or_else(
-            || format!("{PLACEHOLDER:>12}"),
-            |dt| format!("{:>12}", self.fmt_timestamp(dt)),
-        );
+        // Timestamps unsupported by the platform or filesystem, such as birth time on older
+        // kernels, are rendered as a placeholder rather than being substituted.
+        let Some(dt) = datetime.map(DateTime::<Local>::from) else {
+            let formatted_placeholder = format!("{PLACEHOLDER:>12}");
+
+            if let Ok(style) = styles::get_placeholder_style() {
+                return write!(f, "[{}]", style.paint(formatted_placeholder));
+            }
+
+            return write!(f, "[{formatted_placeholder}]");
+        };
+
+        let out = format!("{:>12}", self.fmt_timestamp(dt));
 
         let formatted_datetime = if let Ok(style) = styles::get_datetime_style() {
             style.paint(out).to_string()
@@ -244,15 +389,20 @@ impl<'a> Cell<'a> {
     #[cfg(unix)]
     #[inline]
     fn fmt_timestamp(&self, dt: DateTime<Local>) -> String {
+        if let Some(ref format) = self.ctx.time_custom {
+            return format!("{:>12}", dt.format(format));
+        }
+
         let time_format = self.ctx.time_format();
-        let delayed_format = match time_format {
-            time::Format::Default => dt.format("%d %h %H:%M %g"),
-            time::Format::Iso => dt.format("%Y-%m-%d %H:%M:%S"),
-            time::Format::IsoStrict => dt.format("%Y-%m-%dT%H:%M:%S%Z"),
-            time::Format::Short => dt.format("%Y-%m-%d"),
+        let formatted = match time_format {
+            time::Format::Default => dt.format("%d %h %H:%M %g").to_string(),
+            time::Format::Iso => dt.format("%Y-%m-%d %H:%M:%S").to_string(),
+            time::Format::IsoStrict => dt.format("%Y-%m-%dT%H:%M:%S%Z").to_string(),
+            time::Format::Short => dt.format("%Y-%m-%d").to_string(),
+            time::Format::Relative => relative_timestamp(dt, Local::now()),
         };
 
-        format!("{delayed_format:>12}")
+        format!("{formatted:>12}")
     }
 
     /// Rules on how to format permissions for rendering
@@ -262,10 +412,23 @@ impl<'a> Cell<'a> {
         let node = self.node;
         let ctx = self.ctx;
 
+        // Placeholders for entries truncated by '--max-entries' and entries of archives opened with
+        // '--into-archives' have no permissions of their own.
+        if !node.has_metadata() {
+            let width = if ctx.octal { 4 } else { 11 };
+            let formatted_placeholder = format!("{PLACEHOLDER:<width$}");
+
+            if let Ok(style) = styles::get_placeholder_style() {
+                return write!(f, "{}", style.paint(formatted_placeholder));
+            }
+
+            return write!(f, "{formatted_placeholder}");
+        }
+
         let formatted_perms = if ctx.octal {
             theme::style_oct_permissions(node)
         } else {
-            theme::style_sym_permissions(node)
+            theme::style_sym_permissions(node, ctx.xattr_marker)
         };
 
         write!(f, "{formatted_perms}")
@@ -312,20 +475,7 @@ impl<'a> Cell<'a> {
             return write!(f, "{size:>max_size_width$} {unit:>max_unit_width$}");
         }
 
-        let color = if metric.human_readable {
-            styles::get_du_theme().unwrap().get(unit).unwrap()
-        } else {
-            match ctx.unit {
-                PrefixKind::Si => {
-                    let pre = SiPrefix::from(metric.value);
-                    styles::get_du_theme().unwrap().get(pre.as_str()).unwrap()
-                },
-                PrefixKind::Bin => {
-                    let pre = BinPrefix::from(metric.value);
-                    styles::get_du_theme().unwrap().get(pre.as_str()).unwrap()
-                },
-            }
-        };
+        let color = Self::byte_style(metric, unit, ctx);
 
         let out = color.paint(format!("{size:>max_size_width$} {unit:>max_unit_width$}"));
 
@@ -340,14 +490,105 @@ impl<'a> Cell<'a> {
         ctx: &Context,
     ) -> fmt::Result {
         let max_size_width = ctx.max_size_width;
+        let size = Self::fit_size(metric.to_string(), ctx);
 
         if ctx.no_color() {
-            return write!(f, "{metric:>max_size_width$}");
+            return write!(f, "{size:>max_size_width$}");
         }
 
+        let color = Self::block_style(metric, ctx);
+
+        let out = color.paint(format!("{size:>max_size_width$}"));
+
+        write!(f, "{out}")
+    }
+
+    /// Rules to format disk usage as unit-less values such as word count, lines, and blocks (unix).
+    #[inline]
+    fn fmt_unitless_disk_usage<M: Display>(
+        f: &mut fmt::Formatter<'_>,
+        metric: &M,
+        ctx: &Context,
+    ) -> fmt::Result {
+        let max_size_width = ctx.max_size_width;
+        let size = Self::fit_size(metric.to_string(), ctx);
+
+        if ctx.no_color() {
+            return write!(f, "{size:>max_size_width$}");
+        }
+        let color = Self::unitless_style();
+
+        write!(f, "{}", color.paint(format!("{size:>max_size_width$}")))
+    }
+
+    /// Replaces a unit-less `size` with an overflow marker if it doesn't fit within the width of
+    /// the disk usage column pinned with '--fixed-size-width'. Byte metrics fit themselves.
+    #[inline]
+    fn fit_size(size: String, ctx: &Context) -> String {
+        match ctx.fixed_size_width {
+            Some(width) if size.len() > width => file_size::overflow_marker(width),
+            _ => size,
+        }
+    }
+
+    /// The style from the disk usage theme used to paint `file_size`, which depends on its
+    /// magnitude. Returns `None` if output isn't colorized.
+    pub fn du_style(file_size: &FileSize, ctx: &Context) -> Option<Style> {
+        if ctx.no_color() {
+            return None;
+        }
+
+        let style = match file_size {
+            FileSize::Byte(metric) => {
+                let out = format!("{metric}");
+                let unit = out.rsplit(' ').next().unwrap_or_default();
+                Self::byte_style(metric, unit, ctx)
+            },
+            FileSize::Line(_) | FileSize::Word(_) => *Self::unitless_style(),
+
+            #[cfg(unix)]
+            FileSize::Block(metric) => Self::block_style(metric, ctx),
+        };
+
+        Some(style)
+    }
+
+    /// Style of disk usage reported in bytes where `unit` is the unit it is displayed in.
+    #[inline]
+    fn byte_style(metric: &byte::Metric, unit: &str, ctx: &Context) -> Style {
+        if let Some(style) = Self::gradient_style(metric.value, ctx) {
+            return style;
+        }
+
+        let style = if metric.human_readable {
+            styles::get_du_theme().unwrap().get(unit).unwrap()
+        } else {
+            match ctx.unit {
+                PrefixKind::Si => {
+                    let pre = SiPrefix::from(metric.value);
+                    styles::get_du_theme().unwrap().get(pre.as_str()).unwrap()
+                },
+                PrefixKind::Bin => {
+                    let pre = BinPrefix::from(metric.value);
+                    styles::get_du_theme().unwrap().get(pre.as_str()).unwrap()
+                },
+            }
+        };
+
+        *style
+    }
+
+    /// Style of disk usage reported in blocks.
+    #[cfg(unix)]
+    #[inline]
+    fn block_style(metric: &block::Metric, ctx: &Context) -> Style {
         let bytes = metric.value * u64::from(BLOCK_SIZE_BYTES);
 
-        let color = match ctx.unit {
+        if let Some(style) = Self::gradient_style(bytes, ctx) {
+            return style;
+        }
+
+        let style = match ctx.unit {
             PrefixKind::Si => {
                 let pre = SiPrefix::from(bytes);
                 styles::get_du_theme().unwrap().get(pre.as_str()).unwrap()
@@ -358,26 +599,27 @@ impl<'a> Cell<'a> {
             },
         };
 
-        let out = color.paint(format!("{metric:>max_size_width$}"));
-
-        write!(f, "{out}")
+        *style
     }
 
-    /// Rules to format disk usage as unit-less values such as word count, lines, and blocks (unix).
+    /// Style of disk usage of `bytes` along the gradient given by '--size-gradient' which takes
+    /// precedence over the disk usage theme. Returns `None` if no gradient was provided or if the
+    /// terminal doesn't support 24-bit color.
     #[inline]
-    fn fmt_unitless_disk_usage<M: Display>(
-        f: &mut fmt::Formatter<'_>,
-        metric: &M,
-        ctx: &Context,
-    ) -> fmt::Result {
-        let max_size_width = ctx.max_size_width;
+    fn gradient_style(bytes: u64, ctx: &Context) -> Option<Style> {
+        let range = ctx.size_gradient?;
 
-        if ctx.no_color() {
-            return write!(f, "{metric:>max_size_width$}");
+        if !ctx.truecolor() {
+            return None;
         }
-        let color = styles::get_du_theme().unwrap().get("B").unwrap();
 
-        write!(f, "{}", color.paint(format!("{metric:>max_size_width$}")))
+        Some(styles::gradient(range.position(bytes)).normal())
+    }
+
+    /// Style of unit-less disk usage such as word and line count.
+    #[inline]
+    fn unitless_style() -> &'static Style {
+        styles::get_du_theme().unwrap().get("B").unwrap()
     }
 }
 
@@ -387,6 +629,8 @@ impl Display for Cell<'_> {
             Kind::FileName { prefix: _prefix } => self.fmt_name(f),
             Kind::FilePath => self.fmt_path(f),
             Kind::FileSize => self.fmt_file_size(f),
+            Kind::Percent => self.fmt_percent(f),
+            Kind::Bar => self.fmt_bar(f),
 
             #[cfg(unix)]
             Kind::Ino => self.fmt_ino(f),
@@ -395,7 +639,10 @@ impl Display for Cell<'_> {
             Kind::Nlink => self.fmt_nlink(f),
 
             #[cfg(unix)]
-            Kind::Datetime => self.fmt_datetime(f),
+            Kind::Xattrs => self.fmt_xattrs(f),
+
+            #[cfg(unix)]
+            Kind::Datetime { stamp } => self.fmt_datetime(f, stamp),
 
             #[cfg(unix)]
             Kind::Permissions => self.fmt_permissions(f),
@@ -408,3 +655,53 @@ impl Display for Cell<'_> {
         }
     }
 }
+
+/// Renders how long ago `dt` was relative to `now` e.g. `3d ago`. Timestamps from the future are
+/// treated as `just now` whereas those older than [`RELATIVE_TIMESTAMP_CUTOFF_DAYS`] are rendered
+/// as a date.
+#[cfg(unix)]
+fn relative_timestamp(dt: DateTime<Local>, now: DateTime<Local>) -> String {
+    let elapsed = now.signed_duration_since(dt);
+
+    if elapsed < Duration::minutes(1) {
+        return String::from("just now");
+    }
+
+    if elapsed < Duration::hours(1) {
+        return format!("{}m ago", elapsed.num_minutes());
+    }
+
+    if elapsed < Duration::days(1) {
+        return format!("{}h ago", elapsed.num_hours());
+    }
+
+    if elapsed < Duration::days(30) {
+        return format!("{}d ago", elapsed.num_days());
+    }
+
+    if elapsed < Duration::days(RELATIVE_TIMESTAMP_CUTOFF_DAYS) {
+        return format!("{}mo ago", elapsed.num_days() / 30);
+    }
+
+    dt.format("%Y-%m-%d").to_string()
+}
+
+#[cfg(unix)]
+#[test]
+fn test_relative_timestamp() {
+    use chrono::TimeZone;
+
+    let now = Local.with_ymd_and_hms(2023, 6, 15, 12, 0, 0).unwrap();
+    let ago = |duration: Duration| relative_timestamp(now - duration, now);
+
+    assert_eq!(ago(Duration::seconds(30)), "just now");
+    assert_eq!(ago(Duration::seconds(-30)), "just now");
+    assert_eq!(ago(Duration::minutes(5)), "5m ago");
+    assert_eq!(ago(Duration::hours(2)), "2h ago");
+    assert_eq!(ago(Duration::days(3)), "3d ago");
+    assert_eq!(ago(Duration::days(65)), "2mo ago");
+    assert_eq!(
+        relative_timestamp(Local.with_ymd_and_hms(2021, 1, 2, 0, 0, 0).unwrap(), now),
+        "2021-01-02"
+    );
+}
@@ -35,13 +35,29 @@ impl<'a, T> Row<'a, T> {
     }
 }
 
+/// The disk usage of `node` followed by its percentage of its parent directory's and a bar
+/// depicting said percentage if either was requested.
+fn size_columns(node: &Node, ctx: &Context) -> String {
+    let mut columns = vec![Cell::new(node, ctx, cell::Kind::FileSize).to_string()];
+
+    if ctx.percent {
+        columns.push(Cell::new(node, ctx, cell::Kind::Percent).to_string());
+    }
+
+    if ctx.bars {
+        columns.push(Cell::new(node, ctx, cell::Kind::Bar).to_string());
+    }
+
+    columns.join(" ")
+}
+
 #[cfg(unix)]
 impl Display for Row<'_, Tree> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let node = self.node;
         let ctx = self.ctx;
 
-        let size = Cell::new(node, ctx, cell::Kind::FileSize);
+        let size = size_columns(node, ctx);
         let name = Cell::new(
             node,
             ctx,
@@ -75,7 +91,7 @@ impl Display for Row<'_, Flat> {
         let node = self.node;
         let ctx = self.ctx;
 
-        let size = Cell::new(node, ctx, cell::Kind::FileSize);
+        let size = size_columns(node, ctx);
         let path = Cell::new(node, ctx, cell::Kind::FilePath);
 
         let row = if ctx.long {
@@ -103,7 +119,7 @@ impl Display for Row<'_, Tree> {
         let node = self.node;
         let ctx = self.ctx;
 
-        let size = Cell::new(node, ctx, cell::Kind::FileSize);
+        let size = size_columns(node, ctx);
         let name = Cell::new(
             node,
             ctx,
@@ -130,7 +146,7 @@ impl Display for Row<'_, Flat> {
         let node = self.node;
         let ctx = self.ctx;
 
-        let size = Cell::new(node, ctx, cell::Kind::FileSize);
+        let size = size_columns(node, ctx);
         let path = Cell::new(node, ctx, cell::Kind::FilePath);
 
         let row = format!("{size}   {path}");
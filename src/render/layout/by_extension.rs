@@ -0,0 +1,104 @@
+use crate::{
+    disk_usage::file_size::FileSize,
+    render::{grid::cell::Cell, ByExtension, Engine},
+};
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+};
+
+/// Label for files that don't have an extension.
+const NO_EXTENSION: &str = "(none)";
+
+/// Column headers of the table.
+const HEADERS: [&str; 3] = ["extension", "count", "total size"];
+
+impl Display for Engine<ByExtension> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ctx = self.context();
+        let tree = self.tree();
+        let arena = tree.arena();
+        let root_id = tree.root_id();
+
+        let mut extensions: HashMap<String, (usize, FileSize)> = HashMap::new();
+
+        for node_id in root_id.descendants(arena).skip(1) {
+            let node = arena[node_id].get();
+
+            if node.is_dir() || node.is_symlink() {
+                continue;
+            }
+
+            let Some(file_size) = node.file_size() else {
+                continue;
+            };
+
+            let extension = node.path().extension().map_or_else(
+                || NO_EXTENSION.to_owned(),
+                |ext| ext.to_string_lossy().into_owned(),
+            );
+
+            let (count, total) = extensions
+                .entry(extension)
+                .or_insert_with(|| (0, FileSize::from(ctx)));
+
+            *count += 1;
+            *total += file_size;
+        }
+
+        let mut rows = extensions
+            .into_iter()
+            .map(|(extension, (count, total))| (extension, count, total))
+            .collect::<Vec<_>>();
+
+        rows.sort_by(|(ext_a, _, size_a), (ext_b, _, size_b)| {
+            size_b
+                .value()
+                .cmp(&size_a.value())
+                .then_with(|| ext_a.cmp(ext_b))
+        });
+
+        let formatted_rows = rows
+            .iter()
+            .map(|(extension, count, total)| (extension, count.to_string(), total))
+            .collect::<Vec<_>>();
+
+        let [ext_header, count_header, size_header] = HEADERS;
+
+        let ext_width = formatted_rows
+            .iter()
+            .map(|(ext, _, _)| ext.len())
+            .fold(ext_header.len(), usize::max);
+
+        let count_width = formatted_rows
+            .iter()
+            .map(|(_, count, _)| count.len())
+            .fold(count_header.len(), usize::max);
+
+        let size_width = formatted_rows
+            .iter()
+            .map(|(_, _, total)| total.to_string().len())
+            .fold(size_header.len(), usize::max);
+
+        write!(
+            f,
+            "{ext_header:<ext_width$}  {count_header:>count_width$}  {size_header:>size_width$}"
+        )?;
+
+        for (extension, count, total) in formatted_rows {
+            let size = format!("{:>size_width$}", total.to_string());
+
+            let size = Cell::du_style(total, ctx).map_or_else(
+                || size.clone(),
+                |style| style.paint(size.as_str()).to_string(),
+            );
+
+            write!(
+                f,
+                "\n{extension:<ext_width$}  {count:>count_width$}  {size}"
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{
+    disk_usage::file_size::FileSize,
+    render::{Csv, Engine},
+};
+use indextree::NodeEdge;
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+};
+
+/// Header of the CSV output.
+const HEADER: &str = "path,size_bytes,file_type,depth";
+
+impl Display for Engine<Csv> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ctx = self.context();
+        let tree = self.tree();
+        let arena = tree.arena();
+        let root_id = tree.root_id();
+        let max_depth = ctx.level();
+        let root_path = ctx.dir_canonical();
+
+        write!(f, "{HEADER}")?;
+
+        for edge in root_id.traverse(arena) {
+            let node_id = match edge {
+                NodeEdge::Start(id) => id,
+                NodeEdge::End(_) => continue,
+            };
+
+            let node = arena[node_id].get();
+
+            if node.depth() > max_depth {
+                continue;
+            }
+
+            let path = if node.depth() == 0 {
+                node.file_name().to_string_lossy()
+            } else {
+                node.path()
+                    .strip_prefix(&root_path)
+                    .unwrap_or_else(|_| node.path())
+                    .to_string_lossy()
+            };
+            let size = node
+                .file_size()
+                .and_then(FileSize::bytes)
+                .map_or_else(String::new, |size| size.to_string());
+
+            write!(
+                f,
+                "\n{},{size},{},{}",
+                escape(ctx.strip_display_prefix(&path)),
+                node.file_type_name(),
+                node.depth()
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Escapes a field as per RFC 4180: fields containing commas, double-quotes, or line breaks are
+/// enclosed in double-quotes with any double-quotes within the field doubled.
+fn escape(field: &str) -> Cow<'_, str> {
+    if field.contains([',', '"', '\r', '\n']) {
+        Cow::from(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::from(field)
+    }
+}
+
+#[test]
+fn test_escape() {
+    assert_eq!(escape("plain.txt"), "plain.txt");
+    assert_eq!(escape("a,b.txt"), "\"a,b.txt\"");
+    assert_eq!(escape("say \"hi\".txt"), "\"say \"\"hi\"\".txt\"");
+    assert_eq!(escape("line\nbreak"), "\"line\nbreak\"");
+}
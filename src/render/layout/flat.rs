@@ -35,10 +35,22 @@ impl Display for Engine<Flat> {
             writeln!(f, "{row}")?;
         }
 
-        if !file_count_data.is_empty() {
+        if ctx.total {
+            write!(f, "\n{}", tree.total(ctx))?;
+        } else if !file_count_data.is_empty() {
             write!(f, "\n{}", FileCount::from(file_count_data))?;
         }
 
+        if ctx.max_depth_stat {
+            if let Some(deepest) = tree.max_depth() {
+                write!(f, "\n{deepest}")?;
+            }
+        }
+
+        if let Some(num) = ctx.with_summary {
+            write!(f, "\n\n{}", tree.summary(num))?;
+        }
+
         Ok(())
     }
 }
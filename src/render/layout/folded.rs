@@ -0,0 +1,63 @@
+use crate::{
+    disk_usage::file_size::FileSize,
+    render::{Engine, Folded},
+};
+use indextree::NodeEdge;
+use std::{
+    fmt::{self, Display},
+    path::Path,
+};
+
+/// Separator between the path components that make up a single stack.
+const STACK_SEP: &str = ";";
+
+impl Display for Engine<Folded> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ctx = self.context();
+        let tree = self.tree();
+        let arena = tree.arena();
+        let root_id = tree.root_id();
+        let root_path = ctx.dir_canonical();
+        let root_name = arena[root_id].get().file_name().to_string_lossy();
+
+        let mut lines = vec![];
+
+        for edge in root_id.traverse(arena) {
+            let node_id = match edge {
+                NodeEdge::Start(id) => id,
+                NodeEdge::End(_) => continue,
+            };
+
+            let node = arena[node_id].get();
+
+            if node.is_dir() || node.is_symlink() {
+                continue;
+            }
+
+            let Some(size) = node.file_size().map(FileSize::value) else {
+                continue;
+            };
+
+            let relative_path = node
+                .path()
+                .strip_prefix(&root_path)
+                .unwrap_or_else(|_| node.path())
+                .to_string_lossy();
+
+            let relative_path = Path::new(ctx.strip_display_prefix(&relative_path));
+
+            let stack = std::iter::once(root_name.clone())
+                .chain(
+                    relative_path
+                        .components()
+                        .map(|c| c.as_os_str().to_string_lossy()),
+                )
+                .collect::<Vec<_>>()
+                .join(STACK_SEP);
+
+            lines.push(format!("{stack} {size}"));
+        }
+
+        write!(f, "{}", lines.join("\n"))
+    }
+}
@@ -0,0 +1,49 @@
+use crate::{
+    disk_usage::file_size::FileSize,
+    render::{Engine, Indent},
+};
+use indextree::NodeEdge;
+use std::fmt::{self, Display};
+
+impl Display for Engine<Indent> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ctx = self.context();
+        let tree = self.tree();
+        let arena = tree.arena();
+        let root_id = tree.root_id();
+        let max_depth = ctx.level();
+
+        let mut lines = vec![];
+
+        for edge in root_id.traverse(arena) {
+            let node_id = match edge {
+                NodeEdge::Start(id) => id,
+                NodeEdge::End(_) => continue,
+            };
+
+            let node = arena[node_id].get();
+            let depth = node.depth();
+
+            if depth > max_depth {
+                continue;
+            }
+
+            let mut line = "\t".repeat(depth);
+            line.push_str(&node.file_name().to_string_lossy());
+
+            let size = node
+                .file_size()
+                .map(FileSize::value)
+                .filter(|_| !ctx.suppress_size);
+
+            if let Some(size) = size {
+                line.push('\t');
+                line.push_str(&size.to_string());
+            }
+
+            lines.push(line);
+        }
+
+        write!(f, "{}", lines.join("\n"))
+    }
+}
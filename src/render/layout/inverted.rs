@@ -3,7 +3,6 @@ use crate::{
         grid::{self, Row},
         theme, Engine, Inverted,
     },
-    styles,
     tree::{count::FileCount, Tree},
 };
 use std::fmt::{self, Display};
@@ -70,7 +69,7 @@ impl Display for Engine<Inverted> {
 
                 if next_depth == current_depth + 1 {
                     if last_sibling {
-                        base_prefix_components.push(styles::SEP);
+                        base_prefix_components.push(theme.get("sep").unwrap());
                     } else {
                         let prefix = theme.get("vt").unwrap();
                         base_prefix_components.push(prefix);
@@ -83,10 +82,22 @@ impl Display for Engine<Inverted> {
             }
         }
 
-        if !file_count_data.is_empty() {
+        if ctx.total {
+            write!(f, "\n{}", tree.total(ctx))?;
+        } else if !file_count_data.is_empty() {
             write!(f, "\n{}", FileCount::from(file_count_data))?;
         }
 
+        if ctx.max_depth_stat {
+            if let Some(deepest) = tree.max_depth() {
+                write!(f, "\n{deepest}")?;
+            }
+        }
+
+        if let Some(num) = ctx.with_summary {
+            write!(f, "\n\n{}", tree.summary(num))?;
+        }
+
         Ok(())
     }
 }
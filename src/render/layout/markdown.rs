@@ -0,0 +1,77 @@
+use crate::render::{Engine, Markdown};
+use indextree::NodeEdge;
+use std::fmt::{self, Display};
+
+/// Characters that would otherwise be interpreted as inline Markdown syntax within a list item
+/// such as emphasis, code spans, links, autolinks, raw HTML, and headings.
+const ESCAPED: [char; 16] = [
+    '\\', '`', '*', '_', '{', '}', '[', ']', '(', ')', '<', '>', '#', '!', '|', '~',
+];
+
+/// Characters that would otherwise be interpreted as the marker of a nested list when at the very
+/// start of a list item.
+const LIST_MARKERS: [char; 2] = ['-', '+'];
+
+impl Display for Engine<Markdown> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ctx = self.context();
+        let tree = self.tree();
+        let arena = tree.arena();
+        let root_id = tree.root_id();
+        let max_depth = ctx.level();
+
+        let mut lines = vec![];
+
+        for edge in root_id.traverse(arena) {
+            let node_id = match edge {
+                NodeEdge::Start(id) => id,
+                NodeEdge::End(_) => continue,
+            };
+
+            let node = arena[node_id].get();
+            let depth = node.depth();
+
+            if depth > max_depth || node.is_elided() {
+                continue;
+            }
+
+            let name = escape(&node.file_name().to_string_lossy());
+
+            let mut line = "  ".repeat(depth);
+
+            if node.is_dir() {
+                line.push_str(&format!("- **{name}**"));
+            } else {
+                line.push_str(&format!("- {name}"));
+            }
+
+            if let Some(size) = node.file_size().filter(|_| !ctx.suppress_size) {
+                line.push_str(&format!(" ({size})"));
+            }
+
+            lines.push(line);
+        }
+
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+/// Escapes `name` so that it's rendered verbatim as the content of a Markdown list item.
+fn escape(name: &str) -> String {
+    let mut escaped = String::with_capacity(name.len());
+
+    // Leading digits followed by a '.' would start a nested ordered list.
+    let ordinal_len = name.chars().take_while(char::is_ascii_digit).count();
+
+    for (i, c) in name.chars().enumerate() {
+        let is_list_marker = i == 0 && LIST_MARKERS.contains(&c);
+        let is_ordinal_delim = i == ordinal_len && ordinal_len > 0 && c == '.';
+
+        if ESCAPED.contains(&c) || is_list_marker || is_ordinal_delim {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+
+    escaped
+}
@@ -9,3 +9,24 @@ pub mod flat_inverted;
 
 /// See [`super::Inverted`]
 pub mod inverted;
+
+/// See [`super::Csv`]
+pub mod csv;
+
+/// See [`super::Folded`]
+pub mod folded;
+
+/// See [`super::ByExtension`]
+pub mod by_extension;
+
+/// See [`super::Indent`]
+pub mod indent;
+
+/// See [`super::Top`]
+pub mod top;
+
+/// See [`super::Print0`]
+pub mod print0;
+
+/// See [`super::Markdown`]
+pub mod markdown;
@@ -0,0 +1,35 @@
+use crate::render::{Engine, Print0};
+use indextree::NodeEdge;
+use std::fmt::{self, Display};
+
+impl Display for Engine<Print0> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ctx = self.context();
+        let tree = self.tree();
+        let arena = tree.arena();
+        let root_id = tree.root_id();
+        let max_depth = ctx.level();
+
+        for edge in root_id.traverse(arena) {
+            let node_id = match edge {
+                NodeEdge::Start(id) => id,
+                NodeEdge::End(_) => continue,
+            };
+
+            let node = arena[node_id].get();
+
+            if node.depth() > max_depth || node.is_elided() {
+                continue;
+            }
+
+            // Directories are only of interest when they're all that is left to print.
+            if node.is_dir() != ctx.dirs_only {
+                continue;
+            }
+
+            write!(f, "{}\0", node.path().display())?;
+        }
+
+        Ok(())
+    }
+}
@@ -3,7 +3,6 @@ use crate::{
         grid::{self, Row},
         theme, Engine, Regular,
     },
-    styles,
     tree::{count::FileCount, Tree},
 };
 use indextree::NodeEdge;
@@ -44,7 +43,7 @@ impl Display for Engine<Regular> {
                     let topmost_sibling = id.following_siblings(arena).nth(1).is_none();
 
                     if topmost_sibling {
-                        base_prefix_components.push(styles::SEP);
+                        base_prefix_components.push(theme.get("sep").unwrap());
                     } else {
                         base_prefix_components.push(theme.get("vt").unwrap());
                     }
@@ -94,10 +93,22 @@ impl Display for Engine<Regular> {
             }
         }
 
-        if !file_count_data.is_empty() {
+        if ctx.total {
+            write!(f, "\n{}", tree.total(ctx))?;
+        } else if !file_count_data.is_empty() {
             write!(f, "\n{}", FileCount::from(file_count_data))?;
         }
 
+        if ctx.max_depth_stat {
+            if let Some(deepest) = tree.max_depth() {
+                write!(f, "\n{deepest}")?;
+            }
+        }
+
+        if let Some(num) = ctx.with_summary {
+            write!(f, "\n\n{}", tree.summary(num))?;
+        }
+
         Ok(())
     }
 }
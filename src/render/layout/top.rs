@@ -0,0 +1,30 @@
+use crate::render::{
+    grid::cell::{Cell, Kind},
+    Engine, Top,
+};
+use std::fmt::{self, Display};
+
+impl Display for Engine<Top> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ctx = self.context();
+        let tree = self.tree();
+        let arena = tree.arena();
+
+        let mut lines = vec![];
+
+        for &node_id in tree.top() {
+            let node = arena[node_id].get();
+            let size = Cell::new(node, ctx, Kind::FileSize);
+            let path = node.path().display().to_string();
+            let path = ctx.strip_display_prefix(&path);
+
+            let path = node
+                .style()
+                .map_or_else(|| path.to_owned(), |style| style.paint(path).to_string());
+
+            lines.push(format!("{size} {path}"));
+        }
+
+        write!(f, "{}", lines.join("\n"))
+    }
+}
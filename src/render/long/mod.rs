@@ -1,5 +1,8 @@
 use super::grid::cell::{self, Cell};
-use crate::{context::Context, tree::node::Node};
+use crate::{
+    context::{time, Context},
+    tree::node::Node,
+};
 use std::{convert::From, fmt};
 
 /// Concerned with displaying that actual attributes associated with the long view.
@@ -21,6 +24,7 @@ pub struct Optionals {
     ino: bool,
     #[allow(dead_code)]
     nlink: bool,
+    xattrs: bool,
     #[allow(dead_code)]
     time: bool,
 }
@@ -46,92 +50,82 @@ impl Default for Optionals {
             group: false,
             ino: false,
             nlink: false,
+            xattrs: false,
             time: true,
         }
     }
 }
 
 impl fmt::Display for Display<'_> {
-    /// Formatting the attributes associated with the long view.
+    /// Formatting the attributes associated with the long view. Optional columns are included in
+    /// the following order: ino, permissions, nlink, owner, group, xattrs, and then timestamps.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let Optionals {
-            group, ino, nlink, ..
+            group,
+            ino,
+            nlink,
+            xattrs,
+            ..
         } = self.optional;
         let node = self.node;
         let ctx = self.ctx;
 
-        let perms = Cell::new(node, ctx, cell::Kind::Permissions);
-        let owner = Cell::new(node, ctx, cell::Kind::Owner);
-        let time = Cell::new(node, ctx, cell::Kind::Datetime);
-
-        match (group, ino, nlink) {
-            (false, false, false) => {
-                write!(f, "{perms} {owner} {time}")
-            },
+        let cell = |kind| Cell::new(node, ctx, kind).to_string();
 
-            (true, true, true) => {
-                let group_out = Cell::new(node, ctx, cell::Kind::Group);
-                let ino_out = Cell::new(node, ctx, cell::Kind::Ino);
-                let nlink_out = Cell::new(node, ctx, cell::Kind::Nlink);
+        let time = if ctx.time_all {
+            let [created, modified, accessed] =
+                [time::Stamp::Create, time::Stamp::Mod, time::Stamp::Access]
+                    .map(|stamp| cell(cell::Kind::Datetime { stamp }));
 
-                write!(
-                    f,
-                    "{ino_out} {perms} {nlink_out} {owner} {group_out} {time}"
-                )
-            },
+            format!("{created} {modified} {accessed}")
+        } else {
+            let stamp = ctx.time();
+            cell(cell::Kind::Datetime { stamp })
+        };
 
-            (true, false, false) => {
-                let group_out = Cell::new(node, ctx, cell::Kind::Group);
+        let mut columns = vec![];
 
-                write!(f, "{perms} {owner} {group_out} {time}")
-            },
-
-            (true, true, false) => {
-                let group_out = Cell::new(node, ctx, cell::Kind::Group);
-                let ino_out = Cell::new(node, ctx, cell::Kind::Ino);
-
-                write!(f, "{ino_out} {perms} {owner} {group_out} {time}")
-            },
-
-            (false, false, true) => {
-                let nlink_out = Cell::new(node, ctx, cell::Kind::Nlink);
+        if ino {
+            columns.push(cell(cell::Kind::Ino));
+        }
 
-                write!(f, "{perms} {nlink_out} {owner} {time}")
-            },
+        columns.push(cell(cell::Kind::Permissions));
 
-            (true, false, true) => {
-                let group_out = Cell::new(node, ctx, cell::Kind::Group);
-                let nlink_out = Cell::new(node, ctx, cell::Kind::Nlink);
+        if nlink {
+            columns.push(cell(cell::Kind::Nlink));
+        }
 
-                write!(f, "{perms} {nlink_out} {owner} {group_out} {time}")
-            },
+        columns.push(cell(cell::Kind::Owner));
 
-            (false, true, false) => {
-                let ino_out = Cell::new(node, ctx, cell::Kind::Ino);
+        if group {
+            columns.push(cell(cell::Kind::Group));
+        }
 
-                write!(f, "{ino_out} {perms} {owner} {time}")
-            },
+        if xattrs {
+            columns.push(cell(cell::Kind::Xattrs));
+        }
 
-            (false, true, true) => {
-                let ino_out = Cell::new(node, ctx, cell::Kind::Ino);
-                let nlink_out = Cell::new(node, ctx, cell::Kind::Nlink);
+        columns.push(time);
 
-                write!(f, "{ino_out} {perms} {nlink_out} {owner} {time}")
-            },
-        }
+        write!(f, "{}", columns.join(" "))
     }
 }
 
 impl From<&Context> for Optionals {
     fn from(ctx: &Context) -> Self {
         let Context {
-            group, ino, nlink, ..
+            group,
+            ino,
+            nlink,
+            xattr,
+            ..
         } = *ctx;
 
         Self {
             group,
             ino,
             nlink,
+            xattrs: xattr,
             ..Self::default()
         }
     }
@@ -36,6 +36,31 @@ pub struct Regular;
 /// `tree` command.
 pub struct Inverted;
 
+/// Comma-separated values with one row per file, suitable for spreadsheets.
+pub struct Csv;
+
+/// Folded stacks of every file's path and disk usage, suitable for flamegraph tooling such as
+/// `inferno-flamegraph`.
+pub struct Folded;
+
+/// A table of the number of files and their aggregate disk usage per file extension.
+pub struct ByExtension;
+
+/// Every entry on its own line indented by one tab per level of depth, similar to `tree -i` but
+/// trivial to parse by indentation level.
+pub struct Indent;
+
+/// The largest regular files of the entire traversal, largest first, each with its full path.
+pub struct Top;
+
+/// The unstyled path of every file, each terminated by a NUL byte rather than a newline, suitable
+/// for `xargs -0`.
+pub struct Print0;
+
+/// A nested Markdown bullet list indented by two spaces per level of depth with directories in
+/// bold, suitable for pasting into a README.
+pub struct Markdown;
+
 impl<T> Engine<T> {
     /// Initializes a new [Engine].
     pub const fn new(tree: Tree, ctx: Context) -> Self {
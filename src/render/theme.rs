@@ -5,6 +5,9 @@ use crate::{
 use ansi_term::Style;
 use std::borrow::Cow;
 
+#[cfg(unix)]
+use crate::context::xattr;
+
 type Theme = Box<dyn FnMut(&Node) -> &'static ThemesMap>;
 
 /// Returns a closure that retrieves the regular theme.
@@ -38,9 +41,13 @@ pub fn link_theme_getter() -> Theme {
     })
 }
 
+/// Appended to the name of symlinks that point to one of their own ancestor directories.
+const SYMLINK_CYCLE_MARKER: &str = " (symlink cycle)";
+
 /// Stylizes the input `file_name` with the provided `style`. If `None` is provided then the
 /// underlying `String` is returned unmodified as a [Cow]. If the provided [Node] is a symlink then
-/// it will be styled accordingly.
+/// it will be styled accordingly, with targets that don't exist styled as broken, and marked if it
+/// points to one of its ancestors.
 pub fn stylize_file_name(node: &Node) -> Cow<'_, str> {
     let name = node.file_name();
     let style = node.style();
@@ -56,29 +63,42 @@ pub fn stylize_file_name(node: &Node) -> Cow<'_, str> {
         return name.to_string_lossy();
     };
 
+    let marker = if node.is_symlink_cycle() {
+        SYMLINK_CYCLE_MARKER
+    } else {
+        ""
+    };
+
     if let Some(color) = style {
         let styled_name = color.paint(name.to_string_lossy());
-        let target_name = symlink_target_style.map_or_else(
+        let target_style = if node.is_broken_link() {
+            styles::get_broken_link_style().ok().copied()
+        } else {
+            symlink_target_style
+        };
+
+        let target_name = target_style.map_or_else(
             || Style::default().paint(target_name.to_string_lossy()),
             |style| style.paint(target_name.to_string_lossy()),
         );
 
-        return Cow::from(format!("{styled_name} -> {target_name}"));
+        return Cow::from(format!("{styled_name} -> {target_name}{marker}"));
     }
 
     let link = name.to_string_lossy();
     let target = target_name.to_string_lossy();
-    Cow::from(format!("{link} -> {target}"))
+    Cow::from(format!("{link} -> {target}{marker}"))
 }
 
-/// Styles the symbolic notation of file permissions.
+/// Styles the symbolic notation of file permissions. Whether or not the `@` denoting extended
+/// attributes is shown is determined by `xattr_marker`.
 #[cfg(unix)]
-pub fn style_sym_permissions(node: &Node) -> String {
+pub fn style_sym_permissions(node: &Node, xattr_marker: xattr::Marker) -> String {
     use crate::fs::permissions::FileModeXAttrs;
 
     let perms = node.mode().expect("Expected permissions to be initialized");
 
-    let symb = if node.has_xattrs() {
+    let symb = if xattr_marker.show(node.has_xattrs()) {
         let perm_xattr = FileModeXAttrs(&perms);
         format!("{perm_xattr}")
     } else {
@@ -0,0 +1,160 @@
+use ansi_term::{Color, Style};
+
+/// Background that is assumed when enforcing '--min-contrast' as most terminal emulators default to
+/// a dark background.
+pub const ASSUMED_BACKGROUND: Color = Color::Black;
+
+/// Levels of each channel of the 6x6x6 color cube of the 256-color palette.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// The first 16 colors of the 256-color palette as rendered by xterm.
+const ANSI_PALETTE: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// Approximates the RGB value of `color`. Named and 256-color palette colors are resolved using
+/// xterm's defaults as the actual values depend on the terminal emulator.
+pub fn to_rgb(color: Color) -> (u8, u8, u8) {
+    match color {
+        Color::Black => ANSI_PALETTE[0],
+        Color::Red => ANSI_PALETTE[1],
+        Color::Green => ANSI_PALETTE[2],
+        Color::Yellow => ANSI_PALETTE[3],
+        Color::Blue => ANSI_PALETTE[4],
+        Color::Purple => ANSI_PALETTE[5],
+        Color::Cyan => ANSI_PALETTE[6],
+        Color::White => ANSI_PALETTE[7],
+        Color::Fixed(n @ 0..=15) => ANSI_PALETTE[usize::from(n)],
+        Color::Fixed(n @ 16..=231) => {
+            let n = usize::from(n - 16);
+            (
+                CUBE_LEVELS[n / 36],
+                CUBE_LEVELS[(n / 6) % 6],
+                CUBE_LEVELS[n % 6],
+            )
+        },
+        Color::Fixed(n) => {
+            let gray = 8 + (n - 232) * 10;
+            (gray, gray, gray)
+        },
+        Color::RGB(r, g, b) => (r, g, b),
+    }
+}
+
+/// Relative luminance of `color` as defined by WCAG 2.x ranging from `0.0` for black to `1.0` for
+/// white.
+pub fn relative_luminance(color: Color) -> f64 {
+    let linearize = |channel: u8| {
+        let c = f64::from(channel) / 255.0;
+
+        if c <= 0.039_28 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+
+    let (r, g, b) = to_rgb(color);
+
+    0.0722_f64.mul_add(
+        linearize(b),
+        0.2126_f64.mul_add(linearize(r), 0.7152 * linearize(g)),
+    )
+}
+
+/// Contrast ratio between two colors as defined by WCAG 2.x ranging from `1.0` for identical
+/// colors to `21.0` for black against white.
+pub fn contrast_ratio(a: Color, b: Color) -> f64 {
+    let (lum_a, lum_b) = (relative_luminance(a), relative_luminance(b));
+    let (lighter, darker) = if lum_a > lum_b {
+        (lum_a, lum_b)
+    } else {
+        (lum_b, lum_a)
+    };
+
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+/// Substitutes the foreground of `style` with either white or black, whichever contrasts more,
+/// if its contrast against the background falls below `min_ratio`. The background of `style` is
+/// used if it has one, otherwise `background` is assumed. Styles without a foreground are left
+/// as is.
+pub fn ensure_contrast(style: Style, background: Color, min_ratio: f64) -> Style {
+    let Some(foreground) = style.foreground else {
+        return style;
+    };
+
+    let background = style.background.unwrap_or(background);
+
+    if contrast_ratio(foreground, background) >= min_ratio {
+        return style;
+    }
+
+    let substitute =
+        if contrast_ratio(Color::White, background) >= contrast_ratio(Color::Black, background) {
+            Color::White
+        } else {
+            Color::Black
+        };
+
+    Style {
+        foreground: Some(substitute),
+        ..style
+    }
+}
+
+#[test]
+fn test_contrast_ratio() {
+    let white = Color::RGB(0xff, 0xff, 0xff);
+    let black = Color::RGB(0, 0, 0);
+
+    assert!((contrast_ratio(white, black) - 21.0).abs() < 0.01);
+    assert!((contrast_ratio(black, white) - 21.0).abs() < 0.01);
+    assert!((contrast_ratio(Color::Red, Color::Red) - 1.0).abs() < f64::EPSILON);
+    assert!((relative_luminance(Color::Fixed(16))).abs() < f64::EPSILON);
+    assert!((relative_luminance(Color::Fixed(231)) - 1.0).abs() < 0.001);
+    assert_eq!(to_rgb(Color::Fixed(244)), (128, 128, 128));
+}
+
+#[test]
+fn test_ensure_contrast() {
+    let invisible = Color::RGB(0x10, 0x10, 0x10).bold();
+    let substituted = ensure_contrast(invisible, Color::Black, 4.5);
+
+    assert_eq!(substituted.foreground, Some(Color::White));
+    assert!(
+        substituted.is_bold,
+        "Only the foreground should be substituted"
+    );
+
+    let readable = Color::Yellow.normal();
+    assert_eq!(ensure_contrast(readable, Color::Black, 4.5), readable);
+
+    let on_light = Color::RGB(0xee, 0xee, 0xee).on(Color::White);
+    assert_eq!(
+        ensure_contrast(on_light, Color::Black, 4.5).foreground,
+        Some(Color::Black),
+        "The style's own background should take precedence"
+    );
+
+    let no_foreground = Style::default();
+    assert_eq!(
+        ensure_contrast(no_foreground, Color::Black, 21.0),
+        no_foreground
+    );
+}
@@ -4,6 +4,9 @@ use error::Error;
 use lscolors::LsColors;
 use std::{collections::HashMap, sync::OnceLock};
 
+/// Contrast computations used by '--min-contrast'.
+pub mod contrast;
+
 /// Errors for this module.
 pub mod error;
 
@@ -25,6 +28,18 @@ pub const UPRT: &str = "\u{2514}\u{2500} ";
 /// The `├─` box drawing characters.
 pub const VTRT: &str = "\u{251C}\u{2500} ";
 
+/// ASCII equivalent of [`VT`] used by '--ascii'.
+pub const ASCII_VT: &str = "|  ";
+
+/// ASCII equivalent of [`DRT`] used by '--ascii'.
+pub const ASCII_DRT: &str = ".- ";
+
+/// ASCII equivalent of [`UPRT`] used by '--ascii'.
+pub const ASCII_UPRT: &str = "`- ";
+
+/// ASCII equivalent of [`VTRT`] used by '--ascii'.
+pub const ASCII_VTRT: &str = "+- ";
+
 /// A runtime evaluated static. [`LS_COLORS`] the `LS_COLORS` environment variable to determine what
 /// ANSI colors to use when printing the names of files. If `LS_COLORS` is not set it will fallback
 /// to a default defined in the `lscolors` crate.
@@ -53,6 +68,18 @@ static PERMISSIONS_THEME: OnceLock<HashMap<char, Style>> = OnceLock::new();
 #[cfg(unix)]
 static OCTAL_PERMISSIONS_STYLE: OnceLock<Style> = OnceLock::new();
 
+/// Runtime evaluated static that contains style for names of entries nested at or beyond the
+/// depth given by '--highlight-depth'.
+static DEPTH_WARNING_STYLE: OnceLock<Style> = OnceLock::new();
+
+/// Runtime evaluated static that contains style for names of entries that have the setuid,
+/// setgid, or sticky bit set when '--highlight-special' is used.
+#[cfg(unix)]
+static SPECIAL_BITS_STYLE: OnceLock<Style> = OnceLock::new();
+
+/// Runtime evaluated static that contains style for the targets of broken symlinks.
+static BROKEN_LINK_STYLE: OnceLock<Style> = OnceLock::new();
+
 /// Runtime evaluated static that contains style for the general use placeholder "-".
 static PLACEHOLDER_STYLE: OnceLock<Style> = OnceLock::new();
 
@@ -72,6 +99,10 @@ static GROUP_STYLE: OnceLock<Style> = OnceLock::new();
 #[cfg(unix)]
 static NLINK_STYLE: OnceLock<Style> = OnceLock::new();
 
+/// Runtime evaluated static that contains style for the number of extended attributes.
+#[cfg(unix)]
+static XATTR_STYLE: OnceLock<Style> = OnceLock::new();
+
 /// Runtime evaluated static that contains style for datetime column.
 #[cfg(unix)]
 static DATETIME_STYLE: OnceLock<Style> = OnceLock::new();
@@ -79,17 +110,74 @@ static DATETIME_STYLE: OnceLock<Style> = OnceLock::new();
 /// Map of the names box-drawing elements to their styled strings.
 pub type ThemesMap = HashMap<&'static str, String>;
 
+/// The characters used to draw the branches of the tree. Each glyph of the default and ASCII sets
+/// is three columns wide so that child indentation lines up.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Glyphs {
+    /// Continues a branch past a sibling, [`VT`] by default.
+    pub vertical: String,
+
+    /// Branch to an entry that has siblings yet to be drawn, [`VTRT`] by default.
+    pub branch: String,
+
+    /// Branch to the last entry of the inverted layout, [`UPRT`] by default.
+    pub last_branch: String,
+
+    /// Branch to the topmost entry of the regular layout, [`DRT`] by default.
+    pub top_branch: String,
+
+    /// Padding used where no branch continues, [`SEP`] by default.
+    pub indent: String,
+}
+
+impl Glyphs {
+    /// Glyphs made up only of ASCII characters for terminals that can't render box drawing
+    /// characters.
+    pub fn ascii() -> Self {
+        Self {
+            vertical: ASCII_VT.to_owned(),
+            branch: ASCII_VTRT.to_owned(),
+            last_branch: ASCII_UPRT.to_owned(),
+            top_branch: ASCII_DRT.to_owned(),
+            indent: SEP.to_owned(),
+        }
+    }
+
+    /// Creates a [`ThemesMap`] out of the glyphs with `paint` applied to each branch.
+    fn themes_map<F: Fn(&str) -> String>(&self, paint: F) -> ThemesMap {
+        hash! {
+            "vt" => paint(&self.vertical),
+            "uprt" => paint(&self.last_branch),
+            "drt" => paint(&self.top_branch),
+            "vtrt" => paint(&self.branch),
+            "sep" => self.indent.clone()
+        }
+    }
+}
+
+impl Default for Glyphs {
+    fn default() -> Self {
+        Self {
+            vertical: VT.to_owned(),
+            branch: VTRT.to_owned(),
+            last_branch: UPRT.to_owned(),
+            top_branch: DRT.to_owned(),
+            indent: SEP.to_owned(),
+        }
+    }
+}
+
 /// Initializes both [`LS_COLORS`] and all themes. If `plain` argument is `true` then plain colorless
-/// themes are used and [`LS_COLORS`] won't be initialized.
-pub fn init(plain: bool) {
+/// themes are used and [`LS_COLORS`] won't be initialized. Branches are drawn using `glyphs`.
+pub fn init(plain: bool, glyphs: &Glyphs) {
     #[cfg(windows)]
     let _ = ansi_term::enable_ansi_support();
 
     if plain {
-        init_plain();
+        init_plain(glyphs);
     } else {
         init_ls_colors();
-        init_themes();
+        init_themes(glyphs);
     }
 }
 
@@ -143,6 +231,31 @@ pub fn get_placeholder_style() -> Result<&'static Style, Error<'static>> {
         .ok_or(Error::Uninitialized("PLACEHOLDER_STYLE"))
 }
 
+/// Getter for [`DEPTH_WARNING_STYLE`]. Returns an error if not initialized.
+#[inline]
+pub fn get_depth_warning_style() -> Result<&'static Style, Error<'static>> {
+    DEPTH_WARNING_STYLE
+        .get()
+        .ok_or(Error::Uninitialized("DEPTH_WARNING_STYLE"))
+}
+
+/// Getter for [`SPECIAL_BITS_STYLE`]. Returns an error if not initialized.
+#[cfg(unix)]
+#[inline]
+pub fn get_special_bits_style() -> Result<&'static Style, Error<'static>> {
+    SPECIAL_BITS_STYLE
+        .get()
+        .ok_or(Error::Uninitialized("SPECIAL_BITS_STYLE"))
+}
+
+/// Getter for [`BROKEN_LINK_STYLE`]. Returns an error if not initialized.
+#[inline]
+pub fn get_broken_link_style() -> Result<&'static Style, Error<'static>> {
+    BROKEN_LINK_STYLE
+        .get()
+        .ok_or(Error::Uninitialized("BROKEN_LINK_STYLE"))
+}
+
 /// Getter for [`INO_STYLE`]. Returns an error if not initialized.
 #[cfg(unix)]
 #[inline]
@@ -171,6 +284,13 @@ pub fn get_nlink_style() -> Result<&'static Style, Error<'static>> {
     NLINK_STYLE.get().ok_or(Error::Uninitialized("NLINK_STYLE"))
 }
 
+/// Getter for [`XATTR_STYLE`]. Returns an error if not initialized.
+#[cfg(unix)]
+#[inline]
+pub fn get_xattr_style() -> Result<&'static Style, Error<'static>> {
+    XATTR_STYLE.get().ok_or(Error::Uninitialized("XATTR_STYLE"))
+}
+
 /// Getter for [`DATETIME_STYLE`]. Returns an error if not initialized.
 #[cfg(unix)]
 #[inline]
@@ -180,6 +300,17 @@ pub fn get_datetime_style() -> Result<&'static Style, Error<'static>> {
         .ok_or(Error::Uninitialized("DATETIME_STYLE"))
 }
 
+/// Color along a gradient from green through yellow to red where `position` is a value between
+/// `0.0` for green and `1.0` for red.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn gradient(position: f64) -> Color {
+    let position = position.clamp(0.0, 1.0);
+    let red = (position * 2.0).min(1.0) * 255.0;
+    let green = ((1.0 - position) * 2.0).min(1.0) * 255.0;
+
+    Color::RGB(red.round() as u8, green.round() as u8, 0)
+}
+
 /// Initializes [`LS_COLORS`] by reading in the `LS_COLORS` environment variable. If it isn't set, a
 /// default determined by `lscolors` crate will be used.
 fn init_ls_colors() {
@@ -189,21 +320,11 @@ fn init_ls_colors() {
 }
 
 /// Colorless themes
-fn init_plain() {
-    let theme = hash! {
-        "vt" => VT.to_owned(),
-        "uprt" => UPRT.to_owned(),
-        "drt" => DRT.to_owned(),
-        "vtrt" => VTRT.to_owned()
-    };
-    TREE_THEME.set(theme).unwrap();
+fn init_plain(glyphs: &Glyphs) {
+    let theme = glyphs.themes_map(str::to_owned);
+    TREE_THEME.set(theme.clone()).unwrap();
 
-    let link_theme = hash! {
-        "vt" => VT.to_owned(),
-        "uprt" => UPRT.to_owned(),
-        "drt" => DRT.to_owned(),
-        "vtrt" => VTRT.to_owned()
-    };
+    let link_theme = theme;
     LINK_THEME.set(link_theme).unwrap();
 }
 
@@ -235,6 +356,9 @@ fn init_themes_for_long_view() {
     let nlink_style = Color::RGB(0xdd, 0xa0, 0xdd).normal();
     NLINK_STYLE.set(nlink_style).unwrap();
 
+    let xattr_style = Color::Cyan.normal();
+    XATTR_STYLE.set(xattr_style).unwrap();
+
     let datetime_style = Color::RGB(0xad, 0xff, 0x2f).normal();
     DATETIME_STYLE.set(datetime_style).unwrap();
 
@@ -243,24 +367,17 @@ fn init_themes_for_long_view() {
 
     let group_style = Color::Green.normal();
     GROUP_STYLE.set(group_style).unwrap();
+
+    let special_bits_style = Color::White.on(Color::Red).bold();
+    SPECIAL_BITS_STYLE.set(special_bits_style).unwrap();
 }
 
 /// Initializes all color themes.
-fn init_themes() {
-    let theme = hash! {
-        "vt" => format!("{}", Color::White.paint(VT)),
-        "uprt" => format!("{}", Color::White.paint(UPRT)),
-        "drt" => format!("{}", Color::White.paint(DRT)),
-        "vtrt" => format!("{}", Color::White.paint(VTRT))
-    };
-    TREE_THEME.set(theme).unwrap();
+fn init_themes(glyphs: &Glyphs) {
+    let theme = glyphs.themes_map(|glyph| format!("{}", Color::White.paint(glyph)));
+    TREE_THEME.set(theme.clone()).unwrap();
 
-    let link_theme = hash! {
-        "vt" => format!("{}", Color::White.paint(VT)),
-        "uprt" => format!("{}", Color::White.paint(UPRT)),
-        "drt" => format!("{}", Color::White.paint(DRT)),
-        "vtrt" => format!("{}", Color::White.paint(VTRT))
-    };
+    let link_theme = theme;
     LINK_THEME.set(link_theme).unwrap();
 
     let du_theme = hash! {
@@ -275,6 +392,20 @@ fn init_themes() {
     let placeholder_style = Color::Purple.normal();
     PLACEHOLDER_STYLE.set(placeholder_style).unwrap();
 
+    let depth_warning_style = Color::RGB(0xff, 0x8c, 0x00).bold();
+    DEPTH_WARNING_STYLE.set(depth_warning_style).unwrap();
+
+    let broken_link_style = Color::Red.strikethrough();
+    BROKEN_LINK_STYLE.set(broken_link_style).unwrap();
+
     #[cfg(unix)]
     init_themes_for_long_view();
 }
+
+#[test]
+fn test_gradient() {
+    assert_eq!(gradient(0.0), Color::RGB(0, 255, 0));
+    assert_eq!(gradient(0.5), Color::RGB(255, 255, 0));
+    assert_eq!(gradient(1.0), Color::RGB(255, 0, 0));
+    assert_eq!(gradient(2.0), Color::RGB(255, 0, 0));
+}
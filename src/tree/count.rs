@@ -1,4 +1,6 @@
 use super::Node;
+use crate::disk_usage::file_size::FileSize;
+use ansi_term::Style;
 use std::{
     convert::From,
     fmt::{self, Display},
@@ -7,7 +9,7 @@ use std::{
 
 /// For keeping track of the number of various file-types of [Node]'s chlidren.
 #[allow(clippy::module_name_repetitions)]
-#[derive(Default)]
+#[derive(Clone, Copy, Default)]
 pub struct FileCount {
     pub num_dirs: usize,
     pub num_files: usize,
@@ -17,7 +19,9 @@ pub struct FileCount {
 impl AddAssign<&Node> for FileCount {
     /// Update [Self] with information from [Node].
     fn add_assign(&mut self, rhs: &Node) {
-        if rhs.is_dir() {
+        if let Some(elided) = rhs.elided_file_count() {
+            *self += elided;
+        } else if rhs.is_dir() {
             self.num_dirs += 1;
         } else if rhs.is_symlink() {
             self.num_links += 1;
@@ -30,6 +34,10 @@ impl Add<&Node> for FileCount {
     type Output = Self;
     /// Update [Self] with information from [Node].
     fn add(self, rhs: &Node) -> Self::Output {
+        if let Some(elided) = rhs.elided_file_count() {
+            return self + elided;
+        }
+
         if rhs.is_dir() {
             Self {
                 num_dirs: self.num_dirs + 1,
@@ -117,3 +125,46 @@ impl Display for FileCount {
         write!(f, "{}", components.join(", "))
     }
 }
+
+/// The grand total of a [`Tree`] printed by '--total' consisting of the number of entries of each
+/// file-type along with their aggregate disk usage.
+///
+/// [`Tree`]: super::Tree
+pub struct Total {
+    pub file_count: FileCount,
+    pub size: Option<FileSize>,
+    pub plain: bool,
+}
+
+impl Display for Total {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut components = vec![];
+
+        let file_count = self.file_count.to_string();
+
+        if !file_count.is_empty() {
+            components.push(file_count);
+        }
+
+        if let Some(ref size) = self.size {
+            let output = match size {
+                FileSize::Line(_) => format!("{size} lines"),
+                FileSize::Word(_) => format!("{size} words"),
+                FileSize::Byte(_) => format!("{size}"),
+
+                #[cfg(unix)]
+                FileSize::Block(_) => format!("{size} blocks"),
+            };
+
+            components.push(output);
+        }
+
+        let total = components.join(", ");
+
+        if self.plain {
+            write!(f, "{total}")
+        } else {
+            write!(f, "{}", Style::new().bold().paint(total))
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use super::Node;
+use std::{
+    convert::From,
+    fmt::{self, Display},
+    path::Path,
+};
+
+/// The most deeply nested [Node] of a [`Tree`] along with its depth relative to the root.
+///
+/// [`Tree`]: super::Tree
+pub struct MaxDepth<'a> {
+    pub depth: usize,
+    pub path: &'a Path,
+}
+
+impl<'a> From<&'a Node> for MaxDepth<'a> {
+    fn from(node: &'a Node) -> Self {
+        Self {
+            depth: node.depth(),
+            path: node.path(),
+        }
+    }
+}
+
+impl Display for MaxDepth<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "max depth reached: {} at {}",
+            self.depth,
+            self.path.display()
+        )
+    }
+}
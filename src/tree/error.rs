@@ -5,11 +5,18 @@ use std::io::Error as IoError;
 #[cfg(unix)]
 use crate::fs::permissions::error::Error as PermissionsError;
 
+#[cfg(feature = "zip")]
+use zip::result::ZipError;
+
 /// Errors that may occur while traversing or construction of [`Tree`].
 ///
 /// [`Tree`]: super::Tree
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
+    #[cfg(feature = "zip")]
+    #[error("{0}")]
+    Archive(#[from] ZipError),
+
     #[error("{0}")]
     Context(#[from] CtxError),
 
@@ -22,6 +29,9 @@ pub enum Error {
     #[error("Invalid glob patterns: {0}")]
     InvalidGlobPatterns(#[from] IgnoreError),
 
+    #[error("Metadata of '{0}' was not queried")]
+    MissingMetadata(String),
+
     #[error("Failed to compute root node.")]
     MissingRoot,
 
@@ -31,6 +41,9 @@ pub enum Error {
     #[error("{0}")]
     PathCanonicalization(#[from] IoError),
 
+    #[error("Cannot read root directory '{0}': {1}")]
+    RootUnreadable(String, String),
+
     #[cfg(unix)]
     #[error("{0}")]
     Permissions(#[from] PermissionsError),
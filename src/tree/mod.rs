@@ -5,57 +5,123 @@ use crate::{
     progress::{IndicatorHandle, Message},
     utils,
 };
-use count::FileCount;
+use count::{FileCount, Total};
+use depth::MaxDepth;
 use error::Error;
 use ignore::{WalkBuilder, WalkParallel};
 use indextree::{Arena, NodeId};
 use node::{cmp::NodeComparator, Node};
+use skipped::Skipped;
+use stats::Stats;
 use std::{
-    collections::{HashMap, HashSet},
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet},
     convert::TryFrom,
     fs,
     path::PathBuf,
     result::Result as StdResult,
-    sync::mpsc::{self, Sender},
+    sync::{
+        mpsc::{self, SyncSender},
+        RwLock,
+    },
     thread,
+    time::Instant,
 };
+use summary::Summary;
+use timing::Timing;
 use visitor::{BranchVisitorBuilder, TraversalState};
 
 /// Operations to handle and display aggregate file counts based on their type.
 pub mod count;
 
+/// Reporting of how deeply nested the [Tree] is.
+pub mod depth;
+
 /// Errors related to traversal, [Tree] construction, and the like.
 pub mod error;
 
 /// Contains components of the [`Tree`] data structure that derive from [`ignore::DirEntry`].
 pub mod node;
 
+/// Sparklines of the disk usages of the children of directories printed by '--sparkline'.
+pub mod sparkline;
+
+/// Entries that couldn't be read during traversal.
+pub mod skipped;
+
+/// Counters accumulated during traversal printed by '--stats'.
+pub mod stats;
+
+/// The largest entries of the [Tree] printed by '--with-summary'.
+pub mod summary;
+
+/// Measurements of how long it takes to build and print a [Tree].
+pub mod timing;
+
 /// Custom visitor that operates on each thread during filesystem traversal.
 mod visitor;
 
+/// Tests for [Tree] construction.
+#[cfg(test)]
+mod test;
+
 /// Virtual data structure that represents local file-system hierarchy.
 pub struct Tree {
     arena: Arena<Node>,
     root_id: NodeId,
+    file_count: FileCount,
+    timing: Timing,
+    stats: Stats,
+    skipped: Vec<Skipped>,
+    top: Vec<NodeId>,
 }
 
 pub type Result<T> = StdResult<T, Error>;
 
+/// Entry of the bounded min-heap used to collect the largest files for '--top'. Files of equal
+/// size are ordered by path such that the lexicographically greatest is evicted first.
+type TopEntry = Reverse<(u64, Reverse<PathBuf>, NodeId)>;
+
+/// Number of [Node]s per traversal thread that may be in flight to the thread that assembles the
+/// [Tree] before the traversal threads are made to wait.
+const CHANNEL_CAPACITY_PER_THREAD: usize = 1024;
+
 impl Tree {
     /// Constructor for [Tree].
-    pub const fn new(arena: Arena<Node>, root_id: NodeId) -> Self {
-        Self { arena, root_id }
+    #[must_use]
+    pub const fn new(
+        arena: Arena<Node>,
+        root_id: NodeId,
+        file_count: FileCount,
+        timing: Timing,
+        stats: Stats,
+        skipped: Vec<Skipped>,
+        top: Vec<NodeId>,
+    ) -> Self {
+        Self {
+            arena,
+            root_id,
+            file_count,
+            timing,
+            stats,
+            skipped,
+            top,
+        }
     }
 
     /// Initiates file-system traversal and [Tree] as well as updates the [Context] object with
     /// various properties necessary to render output.
+    ///
+    /// # Errors
+    ///
+    /// See [`Tree::build`].
     pub fn try_init(
         mut ctx: Context,
         indicator: Option<&IndicatorHandle>,
     ) -> Result<(Self, Context)> {
         let mut column_properties = column::Properties::from(&ctx);
 
-        let (arena, root_id) = Self::traverse(&ctx, &mut column_properties, indicator)?;
+        let tree = Self::build(&ctx, &mut column_properties, indicator)?;
 
         ctx.update_column_properties(&column_properties);
 
@@ -63,16 +129,36 @@ impl Tree {
             ctx.set_window_width();
         }
 
-        let tree = Self::new(arena, root_id);
+        Ok((tree, ctx))
+    }
+
+    /// Traverses the file-system and assembles the [Tree] while recording the widths of the
+    /// columns needed to render it in `column_properties`. Unlike [`Tree::try_init`] the
+    /// [Context] is left untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the root directory can't be read, the [Context] is invalid for
+    /// traversal, e.g. due to a malformed pattern, or if a search via '--pattern' yields no
+    /// matches.
+    pub fn build(
+        ctx: &Context,
+        column_properties: &mut column::Properties,
+        indicator: Option<&IndicatorHandle>,
+    ) -> Result<Self> {
+        let traverse_start = Instant::now();
+        let mut tree = Self::traverse(ctx, column_properties, indicator)?;
+        tree.stats.elapsed = traverse_start.elapsed();
 
-        if tree.is_stump() {
+        if tree.is_stump() && ctx.pattern.is_some() {
             return Err(Error::NoMatches);
         }
 
-        Ok((tree, ctx))
+        Ok(tree)
     }
 
     /// Returns `true` if there are no entries to show excluding the `root_id`.
+    #[must_use]
     pub fn is_stump(&self) -> bool {
         self.root_id
             .descendants(self.arena())
@@ -83,11 +169,77 @@ impl Tree {
     }
 
     /// Grab a reference to `root_id`.
+    #[must_use]
     pub const fn root_id(&self) -> NodeId {
         self.root_id
     }
 
+    /// The grand total of the [Tree] consisting of the number of entries of each file-type, which
+    /// is accumulated while the [Tree] is assembled, along with the disk usage of the root. The
+    /// root of an empty [Tree] has no disk usage of its own so it's reported as zero.
+    #[must_use]
+    pub fn total(&self, ctx: &Context) -> Total {
+        let size = (!ctx.suppress_size).then(|| {
+            let mut size = FileSize::from(ctx);
+
+            if let Some(root_size) = self.root().file_size() {
+                size += root_size;
+            }
+
+            size
+        });
+
+        Total {
+            file_count: self.file_count,
+            size,
+            plain: ctx.no_color(),
+        }
+    }
+
+    /// Time spent traversing and assembling the [Tree].
+    #[must_use]
+    pub const fn timing(&self) -> Timing {
+        self.timing
+    }
+
+    /// Counters accumulated during traversal along with the time it took.
+    #[must_use]
+    pub const fn stats(&self) -> Stats {
+        self.stats
+    }
+
+    /// Entries that couldn't be read during traversal and were skipped along with their
+    /// descendants rather than aborting the traversal.
+    #[must_use]
+    pub fn skipped(&self) -> &[Skipped] {
+        &self.skipped
+    }
+
+    /// The largest regular files of the entire traversal, largest first with ties broken by path,
+    /// as requested by '--top'. Empty if '--top' wasn't provided.
+    #[must_use]
+    pub fn top(&self) -> &[NodeId] {
+        &self.top
+    }
+
+    /// Grabs a reference to the [Node] of the root directory.
+    #[must_use]
+    pub fn root(&self) -> &Node {
+        self.arena[self.root_id].get()
+    }
+
+    /// The [Node]s of the immediate children of the [Node] identified by `node_id` in the order
+    /// that they're sorted. Only used by library consumers; the `erd` binary walks the arena
+    /// itself.
+    #[allow(dead_code)]
+    pub fn children(&self, node_id: NodeId) -> impl Iterator<Item = &Node> + '_ {
+        node_id
+            .children(&self.arena)
+            .map(|child_id| self.arena[child_id].get())
+    }
+
     /// Grabs a reference to `arena`.
+    #[must_use]
     pub const fn arena(&self) -> &Arena<Node> {
         &self.arena
     }
@@ -96,13 +248,20 @@ impl Tree {
     /// `WalkParallel`. Any filesystem I/O or related system calls are expected to occur during
     /// parallel traversal; post-processing post-processing of all directory entries should
     /// be completely CPU-bound.
+    ///
+    /// Traversal threads send [Node]s to the assembling thread over a bounded channel. Should the
+    /// channel fill up, traversal threads block until there is room again which applies
+    /// backpressure to the walk rather than letting pending [Node]s grow without bound.
     fn traverse(
         ctx: &Context,
         column_properties: &mut column::Properties,
         indicator: Option<&IndicatorHandle>,
-    ) -> Result<(Arena<Node>, NodeId)> {
+    ) -> Result<Self> {
         let walker = WalkParallel::try_from(ctx)?;
-        let (tx, rx) = mpsc::channel();
+        let traversal_start = Instant::now();
+        // Bounded so that memory doesn't balloon if the walker outpaces assembly; visitors block
+        // on a full channel until the receiver catches up rather than dropping entries.
+        let (tx, rx) = mpsc::sync_channel(ctx.threads() * CHANNEL_CAPACITY_PER_THREAD);
 
         let progress_indicator_mailbox = indicator.map(IndicatorHandle::mailbox);
 
@@ -111,8 +270,23 @@ impl Tree {
                 let mut tree = Arena::new();
                 let mut branches: HashMap<PathBuf, Vec<NodeId>> = HashMap::new();
                 let mut root_id = None;
+                let mut top = BinaryHeap::new();
+                let mut stats = Stats::default();
+                let mut skipped = vec![];
+
+                loop {
+                    let node = match rx.recv() {
+                        Ok(TraversalState::Ongoing(node)) => node,
+                        Ok(TraversalState::Skipped(entry)) => {
+                            stats.skipped += 1;
+                            skipped.push(entry);
+                            continue;
+                        },
+                        Ok(TraversalState::Done) | Err(_) => break,
+                    };
+
+                    stats.entries += 1;
 
-                while let Ok(TraversalState::Ongoing(node)) = rx.recv() {
                     if let Some(ref mailbox) = progress_indicator_mailbox {
                         if mailbox.send(Message::Index).is_err() {
                             return Err(Error::Terminated);
@@ -134,8 +308,20 @@ impl Tree {
 
                     let parent = node.parent_path().ok_or(Error::ExpectedParent)?.to_owned();
 
+                    let top_candidate = ctx
+                        .top
+                        .and_then(|num| Self::top_candidate(&node, num, &top));
+
                     let node_id = tree.new_node(node);
 
+                    if let Some((size, path)) = top_candidate {
+                        top.push(Reverse((size, Reverse(path), node_id)));
+
+                        if ctx.top.is_some_and(|num| top.len() > num) {
+                            top.pop();
+                        }
+                    }
+
                     if branches
                         .get_mut(&parent)
                         .map(|mut_ref| mut_ref.push(node_id))
@@ -151,32 +337,80 @@ impl Tree {
                     }
                 }
 
+                let assembly_start = Instant::now();
+
                 let root_id = root_id.ok_or(Error::MissingRoot)?;
                 let node_comparator = node::cmp::comparator(ctx);
                 let mut inodes = HashSet::new();
+                let mut assembled = vec![];
 
-                Self::assemble_tree(
+                let mut file_count = Self::assemble_tree(
                     &mut tree,
                     root_id,
                     &mut branches,
-                    &node_comparator,
+                    &mut assembled,
                     &mut inodes,
                     column_properties,
                     ctx,
                 );
 
-                if ctx.prune || ctx.pattern.is_some() {
-                    Self::prune_directories(root_id, &mut tree);
+                Self::sort_children(
+                    &mut tree,
+                    assembled,
+                    &node_comparator,
+                    column_properties,
+                    ctx,
+                );
+
+                if ctx.percent || ctx.bars {
+                    tree[root_id].get_mut().set_percent(100.0);
+                }
+
+                if ctx.files_only {
+                    Self::filter_files(root_id, &mut tree, &mut file_count);
+                }
+
+                if ctx.prune
+                    || ctx.files_only
+                    || ctx.pattern.is_some()
+                    || ctx.min_size.is_some()
+                    || ctx.max_size.is_some()
+                {
+                    Self::prune_directories(root_id, &mut tree, &mut file_count);
                 }
 
                 if ctx.dirs_only {
-                    Self::filter_directories(root_id, &mut tree);
+                    Self::filter_directories(root_id, &mut tree, &mut file_count);
                 }
 
-                Ok((tree, root_id))
+                if ctx.auto_unit_column {
+                    Self::unify_size_units(root_id, &mut tree, column_properties, ctx);
+                }
+
+                if ctx.sparkline {
+                    Self::set_sparklines(root_id, &mut tree);
+                }
+
+                stats.bytes = tree[root_id].get().file_size().and_then(FileSize::bytes);
+
+                let timing = Timing {
+                    traversal: assembly_start.duration_since(traversal_start),
+                    assembly: assembly_start.elapsed(),
+                    ..Timing::default()
+                };
+
+                let top = top
+                    .into_sorted_vec()
+                    .into_iter()
+                    .map(|Reverse((_, _, node_id))| node_id)
+                    .collect();
+
+                Ok(Self::new(
+                    tree, root_id, file_count, timing, stats, skipped, top,
+                ))
             });
 
-            let mut visitor_builder = BranchVisitorBuilder::new(ctx, Sender::clone(&tx));
+            let mut visitor_builder = BranchVisitorBuilder::new(ctx, SyncSender::clone(&tx));
 
             walker.visit(&mut visitor_builder);
 
@@ -186,24 +420,58 @@ impl Tree {
         })
     }
 
-    /// Takes the results of the parallel traversal and uses it to construct the [Tree] data
-    /// structure. Sorting occurs if specified. The amount of columns needed to fit all of the disk
-    /// usages is also computed here.
+    /// Returns the disk usage and path of `node` if it is a regular file that belongs among the
+    /// `num` largest files collected so far in the bounded min-heap `top`. Paths are only cloned
+    /// for files that make the cut.
+    fn top_candidate(
+        node: &Node,
+        num: usize,
+        top: &BinaryHeap<TopEntry>,
+    ) -> Option<(u64, PathBuf)> {
+        if node.is_dir() || node.is_symlink() {
+            return None;
+        }
+
+        let size = node.file_size().map(FileSize::value)?;
+
+        let qualifies = top.len() < num
+            || top
+                .peek()
+                .is_some_and(|Reverse((smallest, Reverse(path), _))| {
+                    size > *smallest || (size == *smallest && node.path() < path.as_path())
+                });
+
+        qualifies.then(|| (size, node.path().to_owned()))
+    }
+
+    /// Takes the results of the parallel traversal and aggregates the disk usage of every
+    /// directory. The amount of columns needed to fit all of the disk usages is also computed here.
+    /// The children of every directory are pushed onto `assembled` to be sorted and attached to
+    /// their parent by [`Self::sort_children`]. Returns the [`FileCount`] of all descendants of
+    /// `current_node_id`.
     fn assemble_tree(
         tree: &mut Arena<Node>,
         current_node_id: NodeId,
         branches: &mut HashMap<PathBuf, Vec<NodeId>>,
-        node_comparator: &NodeComparator,
+        assembled: &mut Vec<(NodeId, Vec<NodeId>)>,
         inode_set: &mut HashSet<Inode>,
         column_properties: &mut column::Properties,
         ctx: &Context,
-    ) {
+    ) -> FileCount {
         let current_node = tree[current_node_id].get_mut();
 
-        let mut children = branches.remove(current_node.path()).unwrap();
+        let children = branches.remove(current_node.path()).unwrap();
+
+        let mut file_count = FileCount::default();
 
         let mut dir_size = FileSize::from(ctx);
 
+        let mut extra_dir_sizes = ctx
+            .extra_disk_usages
+            .iter()
+            .map(|&du| (du, FileSize::empty(du, ctx)))
+            .collect::<Vec<_>>();
+
         for child_id in &children {
             let index = *child_id;
 
@@ -213,11 +481,11 @@ impl Tree {
             };
 
             if is_dir {
-                Self::assemble_tree(
+                file_count += Self::assemble_tree(
                     tree,
                     index,
                     branches,
-                    node_comparator,
+                    assembled,
                     inode_set,
                     column_properties,
                     ctx,
@@ -226,6 +494,8 @@ impl Tree {
 
             let node = tree[index].get();
 
+            file_count += node;
+
             #[cfg(unix)]
             Self::update_column_properties(column_properties, node, ctx);
 
@@ -242,14 +512,27 @@ impl Tree {
             if let Some(file_size) = node.file_size() {
                 dir_size += file_size;
             }
+
+            for (du, extra_dir_size) in &mut extra_dir_sizes {
+                if let Some(file_size) = node.size_for(*du) {
+                    *extra_dir_size += file_size;
+                }
+            }
         }
 
-        if dir_size.value() > 0 {
-            let dir = tree[current_node_id].get_mut();
+        let dir = tree[current_node_id].get_mut();
+        let dir_size_value = dir_size.value();
 
+        if dir_size_value > 0 {
             dir.set_file_size(dir_size);
         }
 
+        dir.set_extra_file_sizes(extra_dir_sizes);
+
+        if ctx.percent || ctx.bars {
+            Self::set_percentages(tree, &children, dir_size_value);
+        }
+
         let dir = tree[current_node_id].get();
 
         #[cfg(unix)]
@@ -258,20 +541,165 @@ impl Tree {
         #[cfg(not(unix))]
         Self::update_column_properties(column_properties, dir, ctx);
 
-        children.sort_by(|&id_a, &id_b| {
-            let node_a = tree[id_a].get();
-            let node_b = tree[id_b].get();
-            node_comparator(node_a, node_b)
+        assembled.push((current_node_id, children));
+
+        file_count
+    }
+
+    /// Sorts the children of every directory in `assembled` and appends them to their parent.
+    /// Sorting the children of one directory is independent of sorting those of another so
+    /// directories are divided among '--assemble-threads' threads, which is one by default. Each
+    /// directory's children are sorted exactly as they would be serially so output is identical
+    /// regardless of thread count.
+    ///
+    /// If '--max-entries' is provided then only that many children of each directory are kept
+    /// after sorting; the rest are collapsed into a single placeholder [Node] whose disk usage and
+    /// file count are the sums of theirs. Truncation happens after aggregation so directory sizes
+    /// are unaffected.
+    fn sort_children(
+        tree: &mut Arena<Node>,
+        mut assembled: Vec<(NodeId, Vec<NodeId>)>,
+        node_comparator: &NodeComparator,
+        column_properties: &mut column::Properties,
+        ctx: &Context,
+    ) {
+        let threads = ctx.assemble_threads();
+        let arena = &*tree;
+
+        let sort = |branches: &mut [(NodeId, Vec<NodeId>)]| {
+            for (_, children) in branches {
+                children.sort_by(|&id_a, &id_b| {
+                    let node_a = arena[id_a].get();
+                    let node_b = arena[id_b].get();
+                    node_comparator(node_a, node_b)
+                });
+            }
+        };
+
+        if threads <= 1 || assembled.len() <= 1 {
+            sort(&mut assembled);
+        } else {
+            let chunk_size = assembled.len().div_ceil(threads);
+
+            thread::scope(|s| {
+                for chunk in assembled.chunks_mut(chunk_size) {
+                    s.spawn(move || sort(chunk));
+                }
+            });
+        }
+
+        let truncated = ctx.max_entries.map_or_else(Vec::new, |max_entries| {
+            assembled
+                .iter_mut()
+                .filter(|(_, children)| children.len() > max_entries)
+                .map(|(parent_id, children)| (*parent_id, children.split_off(max_entries)))
+                .collect::<Vec<_>>()
         });
 
-        // Append children to current node.
-        for child_id in children {
-            current_node_id.append(child_id, tree);
+        for (parent_id, children) in assembled {
+            for child_id in children {
+                parent_id.append(child_id, tree);
+            }
         }
+
+        for (parent_id, entries) in truncated {
+            let elided_id = Self::elide(tree, &entries, ctx);
+
+            Self::update_column_properties(column_properties, tree[elided_id].get(), ctx);
+
+            parent_id.append(elided_id, tree);
+        }
+    }
+
+    /// Collapses the non-empty `truncated` children of a directory into a placeholder [Node]
+    /// whose disk usage, percentage, and file count are the sums of theirs. The first of
+    /// `truncated` is reused as the placeholder and its [`NodeId`] is returned. None of
+    /// `truncated` are attached to their parent; any descendants they have are discarded.
+    fn elide(tree: &mut Arena<Node>, truncated: &[NodeId], ctx: &Context) -> NodeId {
+        let mut size = FileSize::from(ctx);
+        let mut percent = None;
+        let mut file_count = FileCount::default();
+
+        for &node_id in truncated {
+            let node = tree[node_id].get();
+
+            if let Some(file_size) = node.file_size() {
+                size += file_size;
+            }
+
+            if let Some(pct) = node.percent() {
+                percent = Some(percent.unwrap_or(0.0) + pct);
+            }
+
+            for descendant_id in node_id.descendants(tree) {
+                file_count += tree[descendant_id].get();
+            }
+        }
+
+        let elided_id = truncated[0];
+
+        let grandchildren = elided_id.children(tree).collect::<Vec<_>>();
+
+        for grandchild_id in grandchildren {
+            grandchild_id.detach(tree);
+        }
+
+        let node = tree[elided_id].get_mut();
+
+        node.elide(truncated.len(), file_count);
+
+        if size.value() > 0 {
+            node.set_file_size(size);
+        }
+
+        if let Some(pct) = percent {
+            node.set_percent(pct);
+        }
+
+        elided_id
     }
 
-    /// Function to remove empty directories.
-    fn prune_directories(root_id: NodeId, tree: &mut Arena<Node>) {
+    /// Sets the disk usage of each of `children` as a percentage of `parent_size`. Nothing is set
+    /// if `parent_size` is zero.
+    fn set_percentages(tree: &mut Arena<Node>, children: &[NodeId], parent_size: u64) {
+        if parent_size == 0 {
+            return;
+        }
+
+        for &child_id in children {
+            let child = tree[child_id].get_mut();
+
+            if let Some(size) = child.file_size().map(FileSize::value) {
+                child.set_percent(size as f64 / parent_size as f64 * 100.0);
+            }
+        }
+    }
+
+    /// Sets the sparkline of each non-empty directory made up of the disk usages of its children
+    /// in the order that they're sorted. This occurs after pruning so that the sparkline reflects
+    /// the children that are actually displayed.
+    fn set_sparklines(root_id: NodeId, tree: &mut Arena<Node>) {
+        let dir_ids = root_id
+            .descendants(tree)
+            .filter(|node_id| tree[*node_id].get().is_dir())
+            .collect::<Vec<_>>();
+
+        for dir_id in dir_ids {
+            let sizes = dir_id
+                .children(tree)
+                .map(|child_id| tree[child_id].get().file_size().map_or(0, FileSize::value))
+                .collect::<Vec<_>>();
+
+            if !sizes.is_empty() {
+                tree[dir_id]
+                    .get_mut()
+                    .set_sparkline(sparkline::levels(&sizes));
+            }
+        }
+    }
+
+    /// Function to remove empty directories. Pruned directories are deducted from `file_count`.
+    fn prune_directories(root_id: NodeId, tree: &mut Arena<Node>, file_count: &mut FileCount) {
         let to_prune = root_id
             .descendants(tree)
             .skip(1)
@@ -288,15 +716,17 @@ impl Tree {
             return;
         }
 
+        file_count.num_dirs -= to_prune.len();
+
         to_prune
             .iter()
             .for_each(|node_id| node_id.remove_subtree(tree));
 
-        Self::prune_directories(root_id, tree);
+        Self::prune_directories(root_id, tree, file_count);
     }
 
-    /// Filter `arena` for only directories.
-    fn filter_directories(root_id: NodeId, tree: &mut Arena<Node>) {
+    /// Filter `arena` for only directories. Only directories remain counted in `file_count`.
+    fn filter_directories(root_id: NodeId, tree: &mut Arena<Node>, file_count: &mut FileCount) {
         let to_detach = root_id
             .descendants(tree)
             .skip(1)
@@ -307,12 +737,116 @@ impl Tree {
             return;
         }
 
+        file_count.num_files = 0;
+        file_count.num_links = 0;
+
         to_detach.iter().for_each(|node_id| node_id.detach(tree));
     }
 
+    /// Filter `arena` for only regular files, keeping directories for structure. Symlinks are only
+    /// kept if they're followed and resolve to regular files. Detached entries are deducted from
+    /// `file_count`; directories left empty are expected to be pruned afterwards.
+    fn filter_files(root_id: NodeId, tree: &mut Arena<Node>, file_count: &mut FileCount) {
+        let to_detach = root_id
+            .descendants(tree)
+            .skip(1)
+            .filter(|&descendant_id| {
+                let node = tree[descendant_id].get();
+
+                // With '--follow' the file-type of a symlink is that of its target.
+                let is_file = node.file_type().is_some_and(|ft| ft.is_file());
+
+                !node.is_dir() && !node.is_elided() && !is_file
+            })
+            .collect::<Vec<_>>();
+
+        for node_id in to_detach {
+            if tree[node_id].get().is_symlink() {
+                file_count.num_links -= 1;
+            } else {
+                file_count.num_files -= 1;
+            }
+
+            node_id.detach(tree);
+        }
+    }
+
+    /// Forces the disk usage of every node to be reported in the unit prefix most suitable for the
+    /// largest disk usage, which is that of the root, recomputing the width of the disk usage
+    /// column accordingly.
+    fn unify_size_units(
+        root_id: NodeId,
+        tree: &mut Arena<Node>,
+        column_properties: &mut column::Properties,
+        ctx: &Context,
+    ) {
+        let Some(max_size) = tree[root_id].get().file_size().map(FileSize::value) else {
+            return;
+        };
+
+        column_properties.max_size_width = 0;
+
+        let node_ids = root_id.descendants(tree).collect::<Vec<_>>();
+
+        for node_id in node_ids {
+            if let Some(FileSize::Byte(metric)) = tree[node_id].get_mut().file_size_mut() {
+                metric.set_prefix_reference(max_size);
+            }
+
+            Self::update_column_properties(column_properties, tree[node_id].get(), ctx);
+        }
+    }
+
+    /// Finds the most deeply nested [Node] of the [Tree]. If several nodes share the greatest
+    /// depth the first one encountered in sorted order is reported.
+    pub fn max_depth(&self) -> Option<MaxDepth<'_>> {
+        self.root_id
+            .descendants(&self.arena)
+            .skip(1)
+            .map(|node_id| self.arena[node_id].get())
+            .fold(None, |deepest: Option<&Node>, node| match deepest {
+                Some(current) if current.depth() >= node.depth() => Some(current),
+                _ => Some(node),
+            })
+            .map(MaxDepth::from)
+    }
+
+    /// The `num` largest entries of the [Tree] excluding the root and placeholders for entries
+    /// truncated by '--max-entries', sorted by disk usage from largest to smallest with ties broken
+    /// by path.
+    #[must_use]
+    pub fn summary(&self, num: usize) -> Summary<'_> {
+        let root_path = self.arena[self.root_id].get().path();
+
+        let mut entries = self
+            .root_id
+            .descendants(&self.arena)
+            .skip(1)
+            .map(|node_id| self.arena[node_id].get())
+            .filter(|node| !node.is_elided())
+            .filter_map(|node| {
+                let path = node.path();
+                let path = path.strip_prefix(root_path).unwrap_or(path);
+                node.file_size().map(|size| (size, path))
+            })
+            .collect::<Vec<_>>();
+
+        entries.sort_by(|(a_size, a_path), (b_size, b_path)| {
+            b_size
+                .value()
+                .cmp(&a_size.value())
+                .then_with(|| a_path.cmp(b_path))
+        });
+
+        entries.truncate(num);
+
+        Summary { entries }
+    }
+
     /// Compute total number of files for a single directory without recurring into child
     /// directories. Files are grouped into three categories: directories, regular files, and
     /// symlinks.
+    #[must_use]
     pub fn compute_file_count(node_id: NodeId, tree: &Arena<Node>) -> FileCount {
         node_id
             .children(tree)
@@ -381,6 +915,14 @@ impl Tree {
                 }
             }
 
+            if let Some(count) = node.xattr_count() {
+                let xattr_num_integral = utils::num_integral(count as u64);
+
+                if xattr_num_integral > col_props.max_xattr_width {
+                    col_props.max_xattr_width = xattr_num_integral;
+                }
+            }
+
             if let Some(blocks) = node.blocks() {
                 let blocks_num_integral = utils::num_integral(blocks);
 
@@ -427,29 +969,83 @@ impl TryFrom<&Context> for WalkParallel {
     fn try_from(ctx: &Context) -> StdResult<Self, Self::Error> {
         let root_id = fs::canonicalize(ctx.dir())?;
 
-        fs::metadata(&root_id)
+        let root_md = fs::metadata(&root_id)
             .map_err(|e| Error::DirNotFound(format!("{}: {e}", root_id.display())))?;
 
-        let mut builder = WalkBuilder::new(root_id);
+        if !root_md.is_dir() {
+            return Err(Error::RootUnreadable(
+                root_id.display().to_string(),
+                String::from("Not a directory"),
+            ));
+        }
+
+        fs::read_dir(&root_id)
+            .map_err(|e| Error::RootUnreadable(root_id.display().to_string(), e.to_string()))?;
+
+        let mut builder = WalkBuilder::new(&root_id);
 
         builder
             .follow_links(ctx.follow)
             .git_ignore(!ctx.no_ignore)
+            .ignore(!ctx.gitignore_only)
             .hidden(!ctx.hidden)
-            .overrides(ctx.no_git_override()?)
+            .overrides(ctx.overrides()?)
             .same_file_system(ctx.same_fs)
-            .threads(ctx.threads);
+            .threads(ctx.threads());
 
         if ctx.suppress_size && ctx.level() == 1 {
             builder.max_depth(Some(1)).threads(1);
         }
 
-        if ctx.pattern.is_some() {
-            if ctx.glob || ctx.iglob {
-                builder.filter_entry(ctx.glob_predicate()?);
-            } else {
-                builder.filter_entry(ctx.regex_predicate()?);
-            }
+        let pattern_predicate = match ctx.pattern {
+            Some(_) if ctx.glob || ctx.iglob => Some(ctx.glob_predicate()?),
+            Some(_) => Some(ctx.regex_predicate()?),
+            None => None,
+        };
+
+        let ignore_files = if ctx.no_ignore {
+            vec![]
+        } else {
+            ctx.ignore_files()?
+        };
+
+        let follow_depth = ctx.follow_depth;
+
+        // Symlinked directories that were descended into. A directory is always filtered before
+        // its children, so the links above an entry are known by the time it's filtered.
+        let followed_links = RwLock::new(HashSet::new());
+
+        if pattern_predicate.is_some() || !ignore_files.is_empty() || follow_depth.is_some() {
+            builder.filter_entry(move |entry| {
+                if let Some(max) = follow_depth {
+                    // Only symlinks above the entry count as the entry itself is shown regardless.
+                    let hops = followed_links
+                        .read()
+                        .map_or(0, |links| crate::fs::link_hops(entry, &links));
+
+                    if hops > max {
+                        return false;
+                    }
+
+                    if entry.path_is_symlink() && entry.file_type().is_some_and(|ft| ft.is_dir()) {
+                        if let Ok(mut links) = followed_links.write() {
+                            links.insert(entry.path().to_path_buf());
+                        }
+                    }
+                }
+
+                let is_dir = entry.file_type().is_some_and(|ft| ft.is_dir());
+
+                // Files provided later take precedence over those provided earlier.
+                let ignored = ignore_files
+                    .iter()
+                    .rev()
+                    .map(|ignore_file| ignore_file.matched(entry.path(), is_dir))
+                    .find(|matched| !matched.is_none())
+                    .is_some_and(|matched| matched.is_ignore());
+
+                !ignored && pattern_predicate.as_ref().map_or(true, |pred| pred(entry))
+            });
         }
 
         Ok(builder.build_parallel())
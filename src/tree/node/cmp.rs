@@ -2,21 +2,24 @@ use super::Node;
 use crate::context::{dir, sort, Context};
 use std::cmp::Ordering;
 
-/// Comparator type used to sort [Node]s.
-pub type NodeComparator = dyn Fn(&Node, &Node) -> Ordering;
+/// Comparator type used to sort [Node]s. Children of different directories are sorted on separate
+/// threads so comparators must be shareable across threads.
+pub type NodeComparator = dyn Fn(&Node, &Node) -> Ordering + Send + Sync;
 
 /// Yields function pointer to the appropriate `Node` comparator.
 pub fn comparator(ctx: &Context) -> Box<NodeComparator> {
-    let sort_type = ctx.sort;
+    let base = composite_comparator(ctx.sort, ctx.sort_then);
+
+    let base = if ctx.reverse {
+        reversed_comparator(base)
+    } else {
+        base
+    };
 
     match ctx.dir_order {
-        dir::Order::First => {
-            Box::new(move |a, b| dir_first_comparator(a, b, base_comparator(sort_type)))
-        },
-        dir::Order::Last => {
-            Box::new(move |a, b| dir_last_comparator(a, b, base_comparator(sort_type)))
-        },
-        dir::Order::None => base_comparator(sort_type),
+        dir::Order::First => Box::new(move |a, b| dir_first_comparator(a, b, &base)),
+        dir::Order::Last => Box::new(move |a, b| dir_last_comparator(a, b, &base)),
+        dir::Order::None => base,
     }
 }
 
@@ -46,6 +49,31 @@ fn dir_last_comparator(
     }
 }
 
+/// Inverts the ordering produced by `comparator`.
+fn reversed_comparator(comparator: Box<NodeComparator>) -> Box<NodeComparator> {
+    Box::new(move |a, b| comparator(a, b).reverse())
+}
+
+/// Grabs the comparator for `sort_type`, using the comparator for `sort_then` to order [Node]s
+/// that `sort_type` considers equal. [Node]s that are still equal are ordered by file name so
+/// that the order is total and doesn't depend on the order in which [Node]s were traversed.
+fn composite_comparator(
+    sort_type: sort::Type,
+    sort_then: Option<sort::Type>,
+) -> Box<NodeComparator> {
+    let primary = base_comparator(sort_type);
+
+    let Some(secondary) = sort_then.map(base_comparator) else {
+        return Box::new(move |a, b| primary(a, b).then_with(|| naming::comparator(a, b)));
+    };
+
+    Box::new(move |a, b| {
+        primary(a, b)
+            .then_with(|| secondary(a, b))
+            .then_with(|| naming::comparator(a, b))
+    })
+}
+
 /// Grabs the comparator for two non-dir type [Node]s.
 fn base_comparator(sort_type: sort::Type) -> Box<NodeComparator> {
     Box::new(match sort_type {
@@ -59,6 +87,7 @@ fn base_comparator(sort_type: sort::Type) -> Box<NodeComparator> {
         sort::Type::Rcreate => time_stamping::created::rev_comparator,
         sort::Type::Mod => time_stamping::modified::comparator,
         sort::Type::Rmod => time_stamping::modified::rev_comparator,
+        sort::Type::Ext => extension::comparator,
     })
 }
 
@@ -135,6 +164,25 @@ mod sizing {
     }
 }
 
+mod extension {
+    use crate::tree::node::Node;
+    use core::cmp::Ordering;
+
+    /// Comparator that groups [Node]s by their case-insensitive file extension and orders them
+    /// by file name within each group. [Node]s without an extension are ordered first.
+    pub fn comparator(a: &Node, b: &Node) -> Ordering {
+        let ext = |node: &Node| {
+            node.path()
+                .extension()
+                .map(|ext| ext.to_string_lossy().to_lowercase())
+        };
+
+        ext(a)
+            .cmp(&ext(b))
+            .then_with(|| super::naming::comparator(a, b))
+    }
+}
+
 mod naming {
     use crate::tree::node::Node;
     use core::cmp::Ordering;
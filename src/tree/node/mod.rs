@@ -1,18 +1,21 @@
 use crate::{
-    context::Context,
+    context::{icon_theme, Context},
     disk_usage::file_size::{byte, line_count, word_count, DiskUsage, FileSize},
     fs::inode::Inode,
     icons,
-    styles::get_ls_colors,
-    tree::error::Error,
+    styles::{contrast, get_depth_warning_style, get_ls_colors, get_placeholder_style},
+    tree::{count::FileCount, error::Error},
 };
 use ansi_term::Style;
-use ignore::DirEntry;
+use ignore::{DirEntry, WalkBuilder};
 use lscolors::Style as LS_Style;
+
+#[cfg(feature = "zip")]
+use lscolors::Indicator;
 use std::{
     borrow::Cow,
     convert::TryFrom,
-    ffi::OsStr,
+    ffi::{OsStr, OsString},
     fs::{FileType, Metadata},
     path::{Path, PathBuf},
     time::SystemTime,
@@ -21,9 +24,23 @@ use std::{
 #[cfg(unix)]
 use crate::{
     disk_usage::file_size::block,
-    fs::permissions::{FileMode, SymbolicNotation},
+    fs::{
+        permissions::{FileMode, SymbolicNotation},
+        xattr::ExtendedAttr,
+    },
+    styles::get_special_bits_style,
 };
 
+#[cfg(unix)]
+use std::os::unix::fs::MetadataExt;
+
+#[cfg(feature = "zip")]
+use crate::fs::archive;
+
+/// Mask of the setuid, setgid, and sticky bits of a file's mode.
+#[cfg(unix)]
+const SPECIAL_BITS: u32 = 0o7000;
+
 /// Ordering and sorting rules for [Node].
 pub mod cmp;
 
@@ -31,6 +48,10 @@ pub mod cmp;
 #[cfg(unix)]
 pub mod unix;
 
+/// Serialization of [Node] with `serde`.
+#[cfg(feature = "serde")]
+mod ser;
+
 /// A node of [`Tree`] that can be created from a [`DirEntry`]. Any filesystem I/O and
 /// relevant system calls are expected to complete after initialization. A `Node` when `Display`ed
 /// uses ANSI colors determined by the file-type and `LS_COLORS`.
@@ -38,22 +59,41 @@ pub mod unix;
 /// [`Tree`]: super::Tree
 pub struct Node {
     dir_entry: DirEntry,
-    metadata: Metadata,
+    metadata: Option<Metadata>,
     file_size: Option<FileSize>,
+    extra_file_sizes: Vec<(DiskUsage, FileSize)>,
+    percent: Option<f64>,
+    sparkline: Option<Vec<u8>>,
     style: Option<Style>,
     symlink_target: Option<PathBuf>,
     symlink_target_style: Option<Style>,
     inode: Option<Inode>,
+    symlink_cycle_depth: Option<usize>,
+    broken_link: bool,
+    elided: Option<(OsString, FileCount)>,
+
+    #[cfg(feature = "zip")]
+    archived: Option<Archived>,
 
     #[cfg(unix)]
     unix_attrs: unix::Attrs,
 }
 
+/// Stands in for the [`DirEntry`] of a [Node] that is an entry of an archive opened with
+/// '--into-archives', or that is the archive itself which is then treated as a directory.
+#[cfg(feature = "zip")]
+struct Archived {
+    path: PathBuf,
+    depth: usize,
+    is_dir: bool,
+}
+
 impl Node {
     /// Initializes a new [Node].
+    #[must_use]
     pub const fn new(
         dir_entry: DirEntry,
-        metadata: Metadata,
+        metadata: Option<Metadata>,
         file_size: Option<FileSize>,
         style: Option<Style>,
         symlink_target: Option<PathBuf>,
@@ -65,18 +105,174 @@ impl Node {
             dir_entry,
             metadata,
             file_size,
+            extra_file_sizes: Vec::new(),
+            percent: None,
+            sparkline: None,
             style,
             symlink_target,
             symlink_target_style,
             inode,
+            symlink_cycle_depth: None,
+            broken_link: false,
+            elided: None,
+            #[cfg(feature = "zip")]
+            archived: None,
             #[cfg(unix)]
             unix_attrs,
         }
     }
 
+    /// Treats the archive that [Node] represents as a directory and returns a [Node] for every
+    /// entry of the archive. Nothing is extracted and entries are reported with their uncompressed
+    /// sizes. Directories precede their contents.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the archive can't be read in which case [Node] is left as is.
+    #[cfg(feature = "zip")]
+    pub fn open_archive(&mut self, ctx: &Context) -> Result<Vec<Self>, Error> {
+        let entries = archive::zip_entries(self.path())?
+            .into_iter()
+            .map(|entry| self.archive_entry(&entry, ctx))
+            .collect();
+
+        self.archived = Some(Archived {
+            path: self.path().to_path_buf(),
+            depth: self.depth(),
+            is_dir: true,
+        });
+
+        Ok(entries)
+    }
+
+    /// Initializes a [Node] for `entry` of the archive that [Node] represents. There is no
+    /// metadata to speak of so only the uncompressed size of `entry` is reported and only if disk
+    /// usage is measured in bytes.
+    #[cfg(feature = "zip")]
+    fn archive_entry(&self, entry: &archive::Entry, ctx: &Context) -> Self {
+        let path = self.path().join(&entry.path);
+        let depth = self.depth() + entry.path.components().count();
+        let is_dir = entry.is_dir();
+
+        let file_size = entry
+            .size
+            .filter(|_| !ctx.suppress_size && ctx.byte_metric())
+            .map(|size| {
+                let mut file_size = FileSize::from(ctx);
+                if let FileSize::Byte(ref mut metric) = file_size {
+                    metric.value = size;
+                }
+                file_size
+            });
+
+        let style = get_ls_colors().ok().and_then(|ls_colors| {
+            let style = if is_dir {
+                ls_colors.style_for_indicator(Indicator::Directory)
+            } else {
+                ls_colors.style_for_path_with_metadata(&path, None)
+            };
+            style.map(LS_Style::to_ansi_term_style)
+        });
+
+        let mut node = Self::new(
+            self.dir_entry.clone(),
+            None,
+            file_size,
+            style,
+            None,
+            None,
+            None,
+            #[cfg(unix)]
+            unix::Attrs::default(),
+        );
+
+        node.archived = Some(Archived {
+            path,
+            depth,
+            is_dir,
+        });
+
+        node
+    }
+
+    /// Whether or not [Node] is an entry of an archive opened with '--into-archives'.
+    #[cfg(feature = "zip")]
+    fn is_archive_entry(&self) -> bool {
+        self.archived
+            .as_ref()
+            .is_some_and(|archived| archived.path != self.dir_entry.path())
+    }
+
+    /// Initializes a [Node] for the symlink at `path` which points to one of its own ancestor
+    /// directories as reported by the parallel walker when '--follow' is enabled. The walker
+    /// refuses to descend into such symlinks and yields an error in place of a [`DirEntry`] so the
+    /// symlink is queried again without following it; `depth` is the depth at which it was found.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the symlink no longer exists or its metadata can't be queried.
+    pub fn try_from_symlink_cycle(path: &Path, depth: usize, ctx: &Context) -> Result<Self, Error> {
+        let dir_entry = WalkBuilder::new(path)
+            .follow_links(false)
+            .standard_filters(false)
+            .max_depth(Some(0))
+            .build()
+            .next()
+            .ok_or_else(|| Error::DirNotFound(path.display().to_string()))??;
+
+        let mut node = Self::try_from((dir_entry, ctx))?;
+        node.symlink_cycle_depth = Some(depth);
+
+        Ok(node)
+    }
+
+    /// Turns [Node] into a placeholder standing in for the `count` entries of its parent directory
+    /// that were truncated by '--max-entries', which along with their descendants amount to
+    /// `file_count`. Anything specific to the underlying [`DirEntry`] is discarded; the caller is
+    /// expected to set the aggregate size of the truncated entries.
+    pub fn elide(&mut self, count: usize, file_count: FileCount) {
+        self.elided = Some((OsString::from(format!("… and {count} more")), file_count));
+        self.metadata = None;
+        self.file_size = None;
+        self.extra_file_sizes = Vec::new();
+        self.percent = None;
+        self.sparkline = None;
+        self.style = get_placeholder_style().ok().copied();
+        self.symlink_target = None;
+        self.symlink_target_style = None;
+        self.inode = None;
+        self.broken_link = false;
+
+        #[cfg(unix)]
+        {
+            self.unix_attrs = unix::Attrs::default();
+        }
+    }
+
+    /// Whether or not [Node] is a placeholder for entries truncated by '--max-entries'.
+    pub const fn is_elided(&self) -> bool {
+        self.elided.is_some()
+    }
+
+    /// The number of entries of each file-type that a placeholder for entries truncated by
+    /// '--max-entries' stands in for, including their descendants.
+    pub fn elided_file_count(&self) -> Option<FileCount> {
+        self.elided.as_ref().map(|(_, file_count)| *file_count)
+    }
+
     /// Returns a reference to `file_name`. If file is a symlink then `file_name` is the name of
-    /// the symlink not the target.
+    /// the symlink not the target. Placeholders for truncated entries are named after the amount
+    /// of entries they stand in for.
     pub fn file_name(&self) -> &OsStr {
+        if let Some((name, _)) = &self.elided {
+            return name;
+        }
+
+        #[cfg(feature = "zip")]
+        if let Some(name) = self.archived.as_ref().and_then(|a| a.path.file_name()) {
+            return name;
+        }
+
         self.dir_entry.file_name()
     }
 
@@ -86,16 +282,25 @@ impl Node {
 
     /// Get depth level of [Node].
     pub fn depth(&self) -> usize {
-        self.dir_entry.depth()
+        #[cfg(feature = "zip")]
+        if let Some(archived) = &self.archived {
+            return archived.depth;
+        }
+
+        self.symlink_cycle_depth
+            .unwrap_or_else(|| self.dir_entry.depth())
+    }
+
+    /// Whether or not [Node] is a symlink that points to one of its own ancestor directories.
+    pub const fn is_symlink_cycle(&self) -> bool {
+        self.symlink_cycle_depth.is_some()
     }
 
     /// Gets the number of blocks used by the underlying [`DirEntry`]. Returns `None` in the case of
     /// no blocks allocated like in the case of directories.
     #[cfg(unix)]
     pub fn blocks(&self) -> Option<u64> {
-        use std::os::unix::fs::MetadataExt;
-
-        let blocks = self.metadata.blocks();
+        let blocks = self.metadata.as_ref()?.blocks();
 
         if blocks == 0 {
             None
@@ -106,17 +311,19 @@ impl Node {
 
     /// Timestamp of when file was last modified.
     pub fn modified(&self) -> Option<SystemTime> {
-        self.metadata.modified().ok()
+        self.metadata.as_ref()?.modified().ok()
     }
 
-    /// Timestamp of when file was created.
+    /// Timestamp of when file was created. On Linux this is the birth time reported by `statx`
+    /// which requires kernel and filesystem support, e.g. ext4 or btrfs; `None` is returned
+    /// otherwise.
     pub fn created(&self) -> Option<SystemTime> {
-        self.metadata.created().ok()
+        self.metadata.as_ref()?.created().ok()
     }
 
     /// Timestamp of when file was last accessed.
     pub fn accessed(&self) -> Option<SystemTime> {
-        self.metadata.accessed().ok()
+        self.metadata.as_ref()?.accessed().ok()
     }
 
     /// Gets the underlying [Inode] of the entry.
@@ -144,8 +351,23 @@ impl Node {
         }
     }
 
+    /// Whether or not the metadata of the underlying [`DirEntry`] was queried when [Node] was
+    /// constructed. See [`Context::metadata_unneeded`].
+    pub const fn has_metadata(&self) -> bool {
+        self.metadata.is_some()
+    }
+
     /// Returns `true` if node is a directory.
     pub fn is_dir(&self) -> bool {
+        if self.is_elided() {
+            return false;
+        }
+
+        #[cfg(feature = "zip")]
+        if let Some(archived) = &self.archived {
+            return archived.is_dir;
+        }
+
         self.file_type().map_or(false, |ft| ft.is_dir())
     }
 
@@ -154,6 +376,11 @@ impl Node {
         self.symlink_target.is_some()
     }
 
+    /// Whether or not [Node] is a symlink whose target doesn't exist.
+    pub const fn is_broken_link(&self) -> bool {
+        self.broken_link
+    }
+
     /// Path to symlink target.
     pub fn symlink_target_path(&self) -> Option<&Path> {
         self.symlink_target.as_deref()
@@ -164,6 +391,17 @@ impl Node {
         self.symlink_target_path().map(Path::as_os_str)
     }
 
+    /// The kind of file that [Node] represents as reported by '--csv'.
+    pub fn file_type_name(&self) -> &'static str {
+        if self.is_symlink() {
+            "symlink"
+        } else if self.is_dir() {
+            "directory"
+        } else {
+            "file"
+        }
+    }
+
     /// Returns reference to underlying [`FileType`].
     pub fn file_type(&self) -> Option<FileType> {
         self.dir_entry.file_type()
@@ -177,6 +415,11 @@ impl Node {
     /// Returns a reference to `path`. If the underlying [`DirEntry`] is a symlink then the path of
     /// the symlink shall be returned.
     pub fn path(&self) -> &Path {
+        #[cfg(feature = "zip")]
+        if let Some(archived) = &self.archived {
+            return &archived.path;
+        }
+
         self.dir_entry.path()
     }
 
@@ -185,15 +428,67 @@ impl Node {
         self.file_size.as_ref()
     }
 
+    /// Gets a mutable reference to '`file_size`'.
+    pub fn file_size_mut(&mut self) -> Option<&mut FileSize> {
+        self.file_size.as_mut()
+    }
+
     /// Sets `file_size`.
     pub fn set_file_size(&mut self, size: FileSize) {
         self.file_size = Some(size);
     }
 
+    /// Gets the disk usage of [Node] as a percentage of that of its parent directory.
+    pub const fn percent(&self) -> Option<f64> {
+        self.percent
+    }
+
+    /// Sets `percent`.
+    pub fn set_percent(&mut self, percent: f64) {
+        self.percent = Some(percent);
+    }
+
+    /// Gets the levels of the sparkline made up of the disk usages of the children of [Node].
+    pub fn sparkline(&self) -> Option<&[u8]> {
+        self.sparkline.as_deref()
+    }
+
+    /// Sets `sparkline`.
+    pub fn set_sparkline(&mut self, levels: Vec<u8>) {
+        self.sparkline = Some(levels);
+    }
+
+    /// Gets the disk usage of [Node] as measured by `disk_usage`. This is available for the
+    /// primary disk usage of [`Context`], which is the same as [`Node::file_size`], as well as for
+    /// the metrics requested via `extra_disk_usages`.
+    pub fn size_for(&self, disk_usage: DiskUsage) -> Option<&FileSize> {
+        self.file_size
+            .as_ref()
+            .filter(|size| size.disk_usage() == disk_usage)
+            .or_else(|| {
+                self.extra_file_sizes
+                    .iter()
+                    .find_map(|(du, size)| (*du == disk_usage).then_some(size))
+            })
+    }
+
+    /// Sets the disk usages measured in addition to `file_size`.
+    pub fn set_extra_file_sizes(&mut self, sizes: Vec<(DiskUsage, FileSize)>) {
+        self.extra_file_sizes = sizes;
+    }
+
     /// Attempts to return an instance of [`FileMode`] for the display of symbolic permissions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if [Node] was initialized without metadata or its mode isn't recognized.
     #[cfg(unix)]
     pub fn mode(&self) -> Result<FileMode, Error> {
-        let permissions = self.metadata.permissions();
+        let metadata = self
+            .metadata
+            .as_ref()
+            .ok_or_else(|| Error::MissingMetadata(self.path().display().to_string()))?;
+        let permissions = metadata.permissions();
         let file_mode = permissions.try_mode_symbolic_notation()?;
         Ok(file_mode)
     }
@@ -204,6 +499,13 @@ impl Node {
         self.unix_attrs.has_xattrs
     }
 
+    /// Returns the number of extended attributes of the [`Node`] if '--xattr' was provided and
+    /// there is at least one.
+    #[cfg(unix)]
+    pub fn xattr_count(&self) -> Option<usize> {
+        self.unix_attrs.xattr_count.filter(|&count| count > 0)
+    }
+
     /// Returns the owner of the [`Node`].
     #[cfg(unix)]
     pub fn owner(&self) -> Option<&str> {
@@ -226,12 +528,75 @@ impl Node {
         self.symlink_target_style
     }
 
-    /// See [`crate::icons::fs::compute`].
-    pub fn compute_icon(&self, no_color: bool) -> Cow<'static, str> {
-        if no_color {
-            icons::fs::compute(self.dir_entry(), self.symlink_target_path())
-        } else {
-            icons::fs::compute_with_color(self.dir_entry(), self.symlink_target_path(), self.style)
+    /// Measures the disk usage of the file at `path` using the metric given by `disk_usage`.
+    fn compute_file_size(
+        disk_usage: DiskUsage,
+        path: &Path,
+        metadata: &Metadata,
+        ctx: &Context,
+    ) -> Option<FileSize> {
+        match disk_usage {
+            DiskUsage::Logical => {
+                let metric = byte::Metric::init_logical(metadata, ctx.unit, ctx.human, ctx.scale)
+                    .with_fixed_unit(ctx.fixed_unit)
+                    .with_max_width(ctx.fixed_size_width);
+                Some(FileSize::Byte(metric))
+            },
+            DiskUsage::Physical => {
+                let metric =
+                    byte::Metric::init_physical(path, metadata, ctx.unit, ctx.human, ctx.scale)
+                        .with_fixed_unit(ctx.fixed_unit)
+                        .with_max_width(ctx.fixed_size_width);
+                Some(FileSize::Byte(metric))
+            },
+            DiskUsage::Line if ctx.loc && !line_count::is_source_file(path) => {
+                Some(FileSize::Line(line_count::Metric::default()))
+            },
+            DiskUsage::Line => {
+                let metric = line_count::Metric::init(path);
+                metric.map(FileSize::Line)
+            },
+            DiskUsage::Word => {
+                let metric = word_count::Metric::init(path);
+                metric.map(FileSize::Word)
+            },
+
+            #[cfg(unix)]
+            DiskUsage::Block => {
+                let metric = block::Metric::init(metadata);
+                Some(FileSize::Block(metric))
+            },
+        }
+    }
+
+    /// See [`crate::icons::fs::compute`] and [`crate::icons::emoji::compute`].
+    pub fn compute_icon(&self, no_color: bool, theme: icon_theme::Type) -> Cow<'static, str> {
+        #[cfg(feature = "zip")]
+        let is_archive_entry = self.is_archive_entry();
+
+        #[cfg(not(feature = "zip"))]
+        let is_archive_entry = false;
+
+        if self.is_elided() || is_archive_entry {
+            return match theme {
+                icon_theme::Type::Emoji => Cow::from(icons::emoji::DEFAULT_EMOJI),
+                icon_theme::Type::Nerd => icons::fs::default_icon(no_color),
+            };
+        }
+
+        match theme {
+            icon_theme::Type::Emoji => Cow::from(icons::emoji::compute(
+                self.dir_entry(),
+                self.symlink_target_path(),
+            )),
+            icon_theme::Type::Nerd if no_color => {
+                icons::fs::compute(self.dir_entry(), self.symlink_target_path())
+            },
+            icon_theme::Type::Nerd => icons::fs::compute_with_color(
+                self.dir_entry(),
+                self.symlink_target_path(),
+                self.style,
+            ),
         }
     }
 }
@@ -246,6 +611,29 @@ impl TryFrom<(DirEntry, &Context)> for Node {
 
         let link_target = crate::fs::symlink_target(&dir_entry);
 
+        // `Path::exists` traverses symlinks so it is false when the target is missing.
+        let broken_link = link_target.is_some() && !path.exists();
+
+        // Nothing downstream depends on metadata so spare ourselves the system call and rely
+        // solely on the file-type reported by the `DirEntry`.
+        if ctx.metadata_unneeded() {
+            let mut node = Self::new(
+                dir_entry,
+                None,
+                None,
+                None,
+                link_target,
+                None,
+                None,
+                #[cfg(unix)]
+                unix::Attrs::default(),
+            );
+
+            node.broken_link = broken_link;
+
+            return Ok(node);
+        }
+
         let metadata = dir_entry.metadata()?;
 
         let (style, link_target_style) = get_ls_colors().ok().map_or_else(
@@ -265,53 +653,75 @@ impl TryFrom<(DirEntry, &Context)> for Node {
             },
         );
 
-        let file_type = dir_entry.file_type();
+        let style = match ctx.highlight_depth {
+            Some(depth) if dir_entry.depth() >= depth => {
+                get_depth_warning_style().ok().copied().or(style)
+            },
+            _ => style,
+        };
 
-        let file_size = match file_type {
-            Some(ref ft)
-                if !ctx.suppress_size && (ft.is_file() || ft.is_symlink() && !ctx.follow) =>
-            {
-                match ctx.disk_usage {
-                    DiskUsage::Logical => {
-                        let metric = byte::Metric::init_logical(&metadata, ctx.unit, ctx.human);
-                        Some(FileSize::Byte(metric))
-                    },
-                    DiskUsage::Physical => {
-                        let metric =
-                            byte::Metric::init_physical(path, &metadata, ctx.unit, ctx.human);
-                        Some(FileSize::Byte(metric))
-                    },
-                    DiskUsage::Line => {
-                        let metric = line_count::Metric::init(path);
-                        metric.map(FileSize::Line)
-                    },
-                    DiskUsage::Word => {
-                        let metric = word_count::Metric::init(path);
-                        metric.map(FileSize::Word)
-                    },
-
-                    #[cfg(unix)]
-                    DiskUsage::Block => {
-                        let metric = block::Metric::init(&metadata);
-                        Some(FileSize::Block(metric))
-                    },
-                }
+        #[cfg(unix)]
+        let style = if ctx.highlight_special && metadata.mode() & SPECIAL_BITS != 0 {
+            get_special_bits_style().ok().copied().or(style)
+        } else {
+            style
+        };
+
+        let (style, link_target_style) = match ctx.min_contrast {
+            Some(ratio) => {
+                let ensure_contrast =
+                    |style| contrast::ensure_contrast(style, contrast::ASSUMED_BACKGROUND, ratio);
+                (
+                    style.map(ensure_contrast),
+                    link_target_style.map(ensure_contrast),
+                )
             },
-            _ => None,
+            None => (style, link_target_style),
+        };
+
+        let file_type = dir_entry.file_type();
+
+        let is_measurable = file_type.map_or(false, |ft| {
+            !ctx.suppress_size && (ft.is_file() || ft.is_symlink() && !ctx.follow)
+        });
+
+        let file_size = is_measurable
+            .then(|| Self::compute_file_size(ctx.disk_usage, path, &metadata, ctx))
+            .flatten();
+
+        let extra_file_sizes = if is_measurable {
+            ctx.extra_disk_usages
+                .iter()
+                .filter_map(|&du| {
+                    Self::compute_file_size(du, path, &metadata, ctx).map(|size| (du, size))
+                })
+                .collect()
+        } else {
+            Vec::new()
         };
 
         let inode = Inode::try_from(&metadata).ok();
 
         #[cfg(unix)]
         let unix_attrs = if ctx.long {
-            unix::Attrs::from((&metadata, &dir_entry))
+            let mut attrs = if ctx.numeric_owner {
+                unix::Attrs::numeric(&metadata, &dir_entry)
+            } else {
+                unix::Attrs::from((&metadata, &dir_entry))
+            };
+
+            if ctx.xattr {
+                attrs.xattr_count = Some(dir_entry.xattr_count());
+            }
+
+            attrs
         } else {
             unix::Attrs::default()
         };
 
-        Ok(Self::new(
+        let mut node = Self::new(
             dir_entry,
-            metadata,
+            Some(metadata),
             file_size,
             style,
             link_target,
@@ -319,6 +729,11 @@ impl TryFrom<(DirEntry, &Context)> for Node {
             inode,
             #[cfg(unix)]
             unix_attrs,
-        ))
+        );
+
+        node.set_extra_file_sizes(extra_file_sizes);
+        node.broken_link = broken_link;
+
+        Ok(node)
     }
 }
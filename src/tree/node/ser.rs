@@ -0,0 +1,18 @@
+use super::Node;
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+/// Serializes the attributes of [Node] that are meaningful outside of rendering. Paths and file
+/// names are serialized lossily as strings as they aren't guaranteed to be valid UTF-8.
+impl Serialize for Node {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Node", 5)?;
+
+        state.serialize_field("file_name", &self.file_name().to_string_lossy())?;
+        state.serialize_field("path", &self.path().to_string_lossy())?;
+        state.serialize_field("file_type", self.file_type_name())?;
+        state.serialize_field("file_size", &self.file_size())?;
+        state.serialize_field("inode", &self.inode())?;
+
+        state.end()
+    }
+}
@@ -1,11 +1,12 @@
 use crate::fs::{ug::UserGroupInfo, xattr::ExtendedAttr};
 use ignore::DirEntry;
-use std::{convert::From, fs::Metadata};
+use std::{convert::From, fs::Metadata, os::unix::fs::MetadataExt};
 
 /// File attributes that are optionally computed and specific to Unix-like systems.
 #[derive(Default)]
 pub struct Attrs {
     pub has_xattrs: bool,
+    pub xattr_count: Option<usize>,
     owner: Option<String>,
     group: Option<String>,
 }
@@ -15,11 +16,22 @@ impl Attrs {
     pub const fn new(has_xattrs: bool, owner: Option<String>, group: Option<String>) -> Self {
         Self {
             has_xattrs,
+            xattr_count: None,
             owner,
             group,
         }
     }
 
+    /// Initializes [`Attrs`] with the raw uid and gid of the file in place of the names of its
+    /// owner and group, sparing the lookups in the user and group databases.
+    pub fn numeric(md: &Metadata, entry: &DirEntry) -> Self {
+        Self::new(
+            entry.has_xattrs(),
+            Some(md.uid().to_string()),
+            Some(md.gid().to_string()),
+        )
+    }
+
     /// Returns the file owner.
     pub fn owner(&self) -> Option<&str> {
         self.owner.as_deref()
@@ -0,0 +1,114 @@
+use super::error::Error;
+use ignore::Error as IgnoreError;
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    path::{Path, PathBuf},
+};
+
+/// An entry that couldn't be read during traversal and was skipped along with its descendants.
+#[derive(Clone, Debug)]
+pub struct Skipped {
+    /// Path of the entry; empty if the walker didn't report one.
+    pub path: PathBuf,
+
+    /// Why the entry couldn't be read e.g. `permission denied`.
+    pub reason: String,
+}
+
+/// The [`Skipped`] entries grouped by reason followed by their paths, as printed to stderr unless
+/// '--quiet' is provided.
+pub struct Report<'a>(pub &'a [Skipped]);
+
+impl Skipped {
+    /// Entry for which a [`Node`] couldn't be constructed.
+    ///
+    /// [`Node`]: super::node::Node
+    pub fn new(path: PathBuf, err: &Error) -> Self {
+        let reason = match err {
+            Error::PathCanonicalization(io_err) => io_err.kind().to_string(),
+            _ => err.to_string(),
+        };
+
+        Self { path, reason }
+    }
+}
+
+impl From<&IgnoreError> for Skipped {
+    fn from(err: &IgnoreError) -> Self {
+        let path = error_path(err).map(Path::to_path_buf).unwrap_or_default();
+
+        let reason = err
+            .io_error()
+            .map_or_else(|| err.to_string(), |io_err| io_err.kind().to_string());
+
+        Self { path, reason }
+    }
+}
+
+impl Display for Report<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut by_reason: BTreeMap<&str, Vec<&Path>> = BTreeMap::new();
+
+        for skipped in self.0 {
+            by_reason
+                .entry(skipped.reason.as_str())
+                .or_default()
+                .push(skipped.path.as_path());
+        }
+
+        let mut lines = vec![];
+
+        for (reason, mut paths) in by_reason {
+            paths.sort_unstable();
+
+            let noun = if paths.len() == 1 { "path" } else { "paths" };
+            lines.push(format!("{} {noun} skipped ({reason})", paths.len()));
+
+            for path in paths {
+                lines.push(format!("  {}", path.display()));
+            }
+        }
+
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+/// Extracts the path of the offending entry if `err` reports one.
+fn error_path(err: &IgnoreError) -> Option<&Path> {
+    match err {
+        IgnoreError::WithPath { path, .. } => Some(path.as_path()),
+        IgnoreError::WithDepth { err, .. } | IgnoreError::WithLineNumber { err, .. } => {
+            error_path(err)
+        },
+        IgnoreError::Loop { child, .. } => Some(child.as_path()),
+        _ => None,
+    }
+}
+
+#[test]
+fn report() {
+    let skipped = |path: &str, reason: &str| Skipped {
+        path: PathBuf::from(path),
+        reason: reason.to_owned(),
+    };
+
+    let report = Report(&[
+        skipped("/b", "permission denied"),
+        skipped("/c", "not found"),
+        skipped("/a", "permission denied"),
+    ])
+    .to_string();
+
+    assert_eq!(
+        report,
+        [
+            "1 path skipped (not found)",
+            "  /c",
+            "2 paths skipped (permission denied)",
+            "  /a",
+            "  /b",
+        ]
+        .join("\n")
+    );
+}
@@ -0,0 +1,67 @@
+use std::fmt::{self, Display};
+
+/// Glyphs of increasing height used to draw a sparkline.
+const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Highest level of a sparkline.
+const MAX_LEVEL: u8 = 7;
+
+/// A sparkline of the disk usages of a directory's children printed by '--sparkline'.
+pub struct Sparkline<'a> {
+    pub levels: &'a [u8],
+
+    /// Levels are drawn as digits rather than block elements.
+    pub ascii: bool,
+}
+
+/// Scales each of `sizes` to a level between `0` and [`MAX_LEVEL`] relative to the largest.
+pub fn levels(sizes: &[u64]) -> Vec<u8> {
+    let max = sizes.iter().copied().max().unwrap_or_default();
+
+    sizes
+        .iter()
+        .map(|&size| {
+            if max == 0 {
+                return 0;
+            }
+
+            let (size, max) = (u128::from(size), u128::from(max));
+            let level = (size * u128::from(MAX_LEVEL) + max / 2) / max;
+
+            u8::try_from(level).unwrap_or(MAX_LEVEL)
+        })
+        .collect()
+}
+
+impl Display for Sparkline<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for &level in self.levels {
+            if self.ascii {
+                write!(f, "{level}")?;
+            } else {
+                write!(f, "{}", BARS[usize::from(level)])?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn test_levels() {
+    assert_eq!(levels(&[]), Vec::<u8>::new());
+    assert_eq!(levels(&[0, 0]), vec![0, 0]);
+    assert_eq!(levels(&[100, 50, 0]), vec![7, 4, 0]);
+
+    let sparkline = Sparkline {
+        levels: &[7, 4, 0],
+        ascii: false,
+    };
+    assert_eq!(sparkline.to_string(), "█▅▁");
+
+    let sparkline = Sparkline {
+        levels: &[7, 4, 0],
+        ascii: true,
+    };
+    assert_eq!(sparkline.to_string(), "740");
+}
@@ -0,0 +1,35 @@
+use std::{
+    fmt::{self, Display},
+    time::Duration,
+};
+
+/// Counters accumulated while traversing the filesystem as reported by '--stats'.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Stats {
+    /// Number of entries received from the traversal threads.
+    pub entries: usize,
+
+    /// Number of entries that couldn't be read and were skipped along with their descendants.
+    pub skipped: usize,
+
+    /// Disk usage of the root directory in bytes; `None` if disk usage isn't measured in bytes.
+    pub bytes: Option<u64>,
+
+    /// Wall-clock time spent traversing the filesystem and assembling the [`Tree`].
+    ///
+    /// [`Tree`]: super::Tree
+    pub elapsed: Duration,
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "entries: {}", self.entries)?;
+        writeln!(f, "skipped: {}", self.skipped)?;
+
+        if let Some(bytes) = self.bytes {
+            writeln!(f, "bytes: {bytes}")?;
+        }
+
+        write!(f, "elapsed: {:?}", self.elapsed)
+    }
+}
@@ -0,0 +1,33 @@
+use crate::disk_usage::file_size::FileSize;
+use std::{
+    fmt::{self, Display},
+    path::Path,
+};
+
+/// The largest entries of a [`Tree`] by disk usage, largest first, that are printed beneath the
+/// tree by '--with-summary'. Paths are relative to the root.
+///
+/// [`Tree`]: super::Tree
+pub struct Summary<'a> {
+    pub entries: Vec<(&'a FileSize, &'a Path)>,
+}
+
+impl Display for Summary<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "largest entries:")?;
+
+        let sizes = self
+            .entries
+            .iter()
+            .map(|(size, _)| size.to_string())
+            .collect::<Vec<_>>();
+
+        let width = sizes.iter().map(String::len).max().unwrap_or_default();
+
+        for (size, (_, path)) in sizes.iter().zip(&self.entries) {
+            write!(f, "\n{size:>width$} {}", path.display())?;
+        }
+
+        Ok(())
+    }
+}
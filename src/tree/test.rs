@@ -0,0 +1,85 @@
+use super::Tree;
+use crate::{
+    context::Context,
+    disk_usage::file_size::{DiskUsage, FileSize},
+};
+use clap::Parser;
+use std::ffi::OsStr;
+
+#[test]
+fn extra_disk_usages() -> Result<(), Box<dyn std::error::Error>> {
+    let mut ctx = Context::try_parse_from(["erd", "--disk-usage", "logical", "tests/data"])?;
+    ctx.extra_disk_usages = vec![DiskUsage::Line, DiskUsage::Word];
+
+    let (tree, _ctx) = Tree::try_init(ctx, None)?;
+    let arena = tree.arena();
+
+    let nemesis = tree
+        .root_id()
+        .descendants(arena)
+        .map(|node_id| arena[node_id].get())
+        .find(|node| node.file_name() == OsStr::new("nemesis.txt"))
+        .expect("Expected 'tests/data/nemesis.txt' to exist");
+
+    assert_eq!(nemesis.file_size().map(FileSize::value), Some(161));
+    assert_eq!(
+        nemesis.size_for(DiskUsage::Logical).map(FileSize::value),
+        Some(161)
+    );
+    assert_eq!(
+        nemesis.size_for(DiskUsage::Line).map(FileSize::value),
+        Some(4)
+    );
+    assert_eq!(
+        nemesis.size_for(DiskUsage::Word).map(FileSize::value),
+        Some(27)
+    );
+    assert!(nemesis.size_for(DiskUsage::Physical).is_none());
+
+    let root = arena[tree.root_id()].get();
+    let root_lines = root.size_for(DiskUsage::Line).map(FileSize::value);
+
+    assert!(root_lines.is_some_and(|lines| lines >= 4));
+
+    Ok(())
+}
+
+#[test]
+fn suppress_size_skips_metadata() -> Result<(), Box<dyn std::error::Error>> {
+    let count_stats = |args: &[&str]| -> Result<(usize, usize), Box<dyn std::error::Error>> {
+        let ctx = Context::try_parse_from(args)?;
+        let (tree, _ctx) = Tree::try_init(ctx, None)?;
+        let arena = tree.arena();
+
+        let nodes = tree
+            .root_id()
+            .descendants(arena)
+            .map(|node_id| arena[node_id].get())
+            .collect::<Vec<_>>();
+
+        let stats = nodes.iter().filter(|node| node.has_metadata()).count();
+
+        Ok((nodes.len(), stats))
+    };
+
+    let (num_nodes, num_stats) = count_stats(&["erd", "--color", "none", "tests/data"])?;
+    assert_eq!(num_nodes, num_stats);
+
+    let (num_structure_nodes, num_structure_stats) =
+        count_stats(&["erd", "--color", "none", "--suppress-size", "tests/data"])?;
+    assert_eq!(num_structure_nodes, num_nodes);
+    assert_eq!(num_structure_stats, 0);
+
+    let (_, num_sorted_stats) = count_stats(&[
+        "erd",
+        "--color",
+        "none",
+        "--suppress-size",
+        "--sort",
+        "mod",
+        "tests/data",
+    ])?;
+    assert_eq!(num_sorted_stats, num_nodes);
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use std::{
+    fmt::{self, Display},
+    time::Duration,
+};
+
+/// Wall-clock time spent in each phase of building and printing a [`Tree`] as reported by
+/// '--timing'.
+///
+/// [`Tree`]: super::Tree
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Timing {
+    /// Time spent traversing the filesystem.
+    pub traversal: Duration,
+
+    /// Time spent assembling, sorting, and pruning the tree after traversal.
+    pub assembly: Duration,
+
+    /// Time spent rendering the output.
+    pub rendering: Duration,
+}
+
+impl Display for Timing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "traversal: {:?}", self.traversal)?;
+        writeln!(f, "assembly: {:?}", self.assembly)?;
+        write!(f, "rendering: {:?}", self.rendering)
+    }
+}
@@ -1,31 +1,37 @@
-use std::sync::mpsc::Sender;
+use std::{path::Path, sync::mpsc::SyncSender};
 
-use crate::{tree::node::Node, Context};
+use crate::{
+    fs,
+    tree::{node::Node, skipped::Skipped},
+    Context,
+};
 use ignore::{DirEntry, Error as IgnoreError, ParallelVisitor, ParallelVisitorBuilder, WalkState};
 
 pub enum TraversalState {
     Ongoing(Node),
+    /// An entry that couldn't be read; it is skipped along with its descendants.
+    Skipped(Skipped),
     Done,
 }
 
 pub struct Branch<'a> {
     ctx: &'a Context,
-    tx: Sender<TraversalState>,
+    tx: SyncSender<TraversalState>,
 }
 
 pub struct BranchVisitorBuilder<'a> {
     ctx: &'a Context,
-    tx: Sender<TraversalState>,
+    tx: SyncSender<TraversalState>,
 }
 
 impl<'a> BranchVisitorBuilder<'a> {
-    pub fn new(ctx: &'a Context, tx: Sender<TraversalState>) -> Self {
+    pub fn new(ctx: &'a Context, tx: SyncSender<TraversalState>) -> Self {
         Self { ctx, tx }
     }
 }
 
 impl<'a> Branch<'a> {
-    pub fn new(ctx: &'a Context, tx: Sender<TraversalState>) -> Self {
+    pub fn new(ctx: &'a Context, tx: SyncSender<TraversalState>) -> Self {
         Self { ctx, tx }
     }
 }
@@ -38,22 +44,129 @@ impl From<Node> for TraversalState {
 
 impl ParallelVisitor for Branch<'_> {
     fn visit(&mut self, entry: Result<DirEntry, IgnoreError>) -> WalkState {
-        let Ok(dir_entry) = entry else {
-            return WalkState::Skip;
+        let dir_entry = match entry {
+            Ok(dir_entry) => dir_entry,
+            Err(err) => return self.visit_symlink_cycle(&err),
         };
 
+        if self.ctx.text_only
+            && dir_entry.file_type().map_or(false, |ft| ft.is_file())
+            && fs::is_binary(dir_entry.path())
+        {
+            return WalkState::Continue;
+        }
+
+        // Kept around only to report the entry should it fail to become a [Node].
+        let path = dir_entry.path().to_path_buf();
+
         match Node::try_from((dir_entry, self.ctx)) {
             Ok(node) => {
+                if !within_size_bounds(&node, self.ctx) {
+                    return WalkState::Continue;
+                }
+
+                if self.ctx.no_broken && node.is_broken_link() {
+                    return WalkState::Continue;
+                }
+
+                #[cfg(feature = "zip")]
+                if self.ctx.into_archives
+                    && node.file_type().is_some_and(|ft| ft.is_file())
+                    && fs::archive::is_archive(node.path())
+                {
+                    return self.visit_archive(node);
+                }
+
                 if self.tx.send(TraversalState::from(node)).is_err() {
                     return WalkState::Quit;
                 }
                 WalkState::Continue
             },
-            _ => WalkState::Skip,
+            Err(err) => self.skip(Skipped::new(path, &err)),
         }
     }
 }
 
+impl Branch<'_> {
+    /// When '--follow' is enabled, symlinks that point to one of their own ancestors are reported
+    /// by the walker as errors rather than being descended into. These are still sent along to be
+    /// rendered as leaves; any other error results in the entry being skipped.
+    fn visit_symlink_cycle(&self, err: &IgnoreError) -> WalkState {
+        let Some((path, depth)) = symlink_cycle(err) else {
+            return self.skip(Skipped::from(err));
+        };
+
+        let node = match Node::try_from_symlink_cycle(path, depth, self.ctx) {
+            Ok(node) => node,
+            Err(node_err) => return self.skip(Skipped::new(path.to_path_buf(), &node_err)),
+        };
+
+        if self.tx.send(TraversalState::from(node)).is_err() {
+            return WalkState::Quit;
+        }
+
+        WalkState::Skip
+    }
+
+    /// With '--into-archives' an archive is sent along as a directory followed by its entries.
+    /// Archives that can't be read are sent along as regular files instead.
+    #[cfg(feature = "zip")]
+    fn visit_archive(&self, mut node: Node) -> WalkState {
+        let entries = node.open_archive(self.ctx).unwrap_or_default();
+
+        let nodes = std::iter::once(node).chain(
+            entries
+                .into_iter()
+                .filter(|entry| within_size_bounds(entry, self.ctx)),
+        );
+
+        for node in nodes {
+            if self.tx.send(TraversalState::from(node)).is_err() {
+                return WalkState::Quit;
+            }
+        }
+
+        WalkState::Continue
+    }
+
+    /// Informs the assembling thread that an entry couldn't be read before skipping it.
+    fn skip(&self, skipped: Skipped) -> WalkState {
+        if self.tx.send(TraversalState::Skipped(skipped)).is_err() {
+            return WalkState::Quit;
+        }
+
+        WalkState::Skip
+    }
+}
+
+/// Extracts the path and depth of the offending symlink if `err` reports a symlink cycle.
+fn symlink_cycle(err: &IgnoreError) -> Option<(&Path, usize)> {
+    match err {
+        IgnoreError::WithDepth { depth, err } => match err.as_ref() {
+            IgnoreError::Loop { child, .. } => Some((child.as_path(), *depth)),
+            _ => None,
+        },
+        IgnoreError::WithPath { err, .. } => symlink_cycle(err),
+        _ => None,
+    }
+}
+
+/// Whether or not a regular file's disk usage is within the bounds set by '--min-size' and
+/// '--max-size'. Disk usage is compared in bytes unless it is reported as a line or word count.
+fn within_size_bounds(node: &Node, ctx: &Context) -> bool {
+    if ctx.min_size.is_none() && ctx.max_size.is_none() {
+        return true;
+    }
+
+    if !node.file_type().map_or(false, |ft| ft.is_file()) {
+        return true;
+    }
+
+    node.file_size().map_or(true, |size| {
+        ctx.size_within_bounds(size.bytes().unwrap_or_else(|| size.value()))
+    })
+}
+
 impl<'s> ParallelVisitorBuilder<'s> for BranchVisitorBuilder<'s> {
     fn build(&mut self) -> Box<dyn ParallelVisitor + 's> {
         let visitor = Branch::new(self.ctx, self.tx.clone());
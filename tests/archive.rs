@@ -0,0 +1,39 @@
+#![cfg(feature = "zip")]
+
+use indoc::indoc;
+
+mod utils;
+
+#[test]
+fn into_archives() {
+    assert_eq!(
+        utils::run_cmd(&["--into-archives", "tests/archives"]),
+        indoc!(
+            "53 B          ┌─ hali.md
+             53 B       ┌─ lake
+             53 B    ┌─ yhtill
+            117 B    │  ┌─ cassilda.txt
+            117 B    ├─ hastur
+             25 B    ├─ camilla.txt
+            195 B ┌─ carcosa.zip
+            195 B archives
+
+            4 directories, 3 files"
+        ),
+        "Entries of the archive should be listed with their uncompressed sizes"
+    );
+}
+
+#[test]
+fn archives_unopened() {
+    assert_eq!(
+        utils::run_cmd(&["tests/archives"]),
+        indoc!(
+            "559 B ┌─ carcosa.zip
+            559 B archives
+
+            1 file"
+        ),
+        "Archives should only be opened with '--into-archives'"
+    );
+}
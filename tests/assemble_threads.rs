@@ -0,0 +1,33 @@
+use std::{error::Error, fs};
+
+mod utils;
+
+#[test]
+fn assemble_threads_deterministic() -> Result<(), Box<dyn Error>> {
+    let tmp = tempfile::TempDir::new()?;
+    let root = tmp.path().join("wide");
+
+    for i in 0..64 {
+        let dir = root.join(format!("dir_{i:02}"));
+        fs::create_dir_all(&dir)?;
+
+        for j in 0..16 {
+            let contents = "x".repeat((i * 7 + j * 13) % 50);
+            fs::write(dir.join(format!("file_{j:02}.txt")), contents)?;
+        }
+    }
+
+    let root = root.to_string_lossy();
+
+    for sort in ["name", "size"] {
+        let serial = utils::run_cmd(&["--sort", sort, "--assemble-threads", "1", &root]);
+        let parallel = utils::run_cmd(&["--sort", sort, "--assemble-threads", "8", &root]);
+
+        assert_eq!(
+            serial, parallel,
+            "Sorting across threads should produce the same output as sorting serially"
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+mod utils;
+
+#[test]
+fn bars_ascii() {
+    let rows = [
+        (" 143 B", "#", "the_yellow_king"),
+        (" 100 B", "", "nylarlathotep.txt"),
+        (" 161 B", "#", "nemesis.txt"),
+        ("  83 B", "", "necronomicon.txt"),
+        (" 446 B", "###", "lipsum"),
+        (" 308 B", "##", "dream_cycle"),
+        ("1241 B", "##########", "data"),
+    ]
+    .map(|(size, bar, path)| format!("{size} {bar:<10}   {path}"))
+    .join("\n");
+
+    assert_eq!(
+        utils::run_cmd(&[
+            "--bars",
+            "--ascii",
+            "--layout",
+            "flat",
+            "--level",
+            "1",
+            "tests/data"
+        ]),
+        format!("{}\n\n3 directories, 6 files", rows.trim_start()),
+        "Expected bars proportional to the disk usage of the parent directory"
+    )
+}
+
+#[test]
+fn bars_partial() {
+    let out = utils::run_cmd(&["--bars", "--layout", "flat", "tests/data"]);
+
+    let bar_for = |path: &str| {
+        out.lines()
+            .find(|line| line.ends_with(&format!("   {path}")))
+            .and_then(|line| line.split(" B ").nth(1))
+            .map(|rest| rest.trim_end_matches(path).trim_end().to_owned())
+            .unwrap_or_else(|| panic!("Expected '{path}' in output"))
+    };
+
+    assert_eq!(bar_for("lipsum/lipsum.txt"), "██████████");
+    assert_eq!(bar_for("lipsum"), "███▋");
+    assert_eq!(bar_for("nylarlathotep.txt"), "▊");
+}
@@ -0,0 +1,54 @@
+use indoc::indoc;
+
+mod utils;
+
+#[test]
+fn by_extension() {
+    assert_eq!(
+        utils::run_cmd(&["--by-extension", "tests/data"]),
+        indoc!(
+            "extension  count  total size
+            txt            5      1098 B
+            md             1       143 B"
+        ),
+        "Failed to aggregate disk usage by extension"
+    )
+}
+
+#[test]
+fn by_extension_human() {
+    assert_eq!(
+        utils::run_cmd(&["--by-extension", "--human", "tests/data"]),
+        indoc!(
+            "extension  count  total size
+            txt            5     1.1 KiB
+            md             1       143 B"
+        ),
+        "Failed to aggregate human-readable disk usage by extension"
+    )
+}
+
+#[test]
+fn by_extension_no_extension() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp = tempfile::TempDir::new()?;
+    let dir = tmp.path().join("exts");
+
+    std::fs::create_dir(&dir)?;
+    std::fs::write(dir.join("Makefile"), "all:")?;
+    std::fs::write(dir.join("a.rs"), "fn a() {}")?;
+    std::fs::write(dir.join("b.rs"), "fn b() {}")?;
+
+    let dir = dir.to_string_lossy();
+
+    assert_eq!(
+        utils::run_cmd(&["--by-extension", &dir]),
+        indoc!(
+            "extension  count  total size
+            rs             2        18 B
+            (none)         1         4 B"
+        ),
+        "Files without an extension should be grouped together"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+mod utils;
+
+/// Runs `erd` with stdout piped, i.e. not a tty, with only the provided color related environment
+/// variables set, returning whether or not the output contains ANSI escapes.
+fn is_colorized(args: &[&str], envs: &[(&str, &str)]) -> bool {
+    let args = args
+        .iter()
+        .copied()
+        .chain(["tests/data"])
+        .collect::<Vec<_>>();
+    let output = utils::run_cmd_raw(&args, envs);
+
+    assert!(output.status.success(), "{}", output.stderr);
+
+    output.stdout.contains('\x1b')
+}
+
+#[test]
+fn clicolor_unset() {
+    assert!(!is_colorized(&[], &[]), "Output to a pipe should be plain");
+}
+
+#[test]
+fn clicolor_force() {
+    assert!(is_colorized(&[], &[("CLICOLOR_FORCE", "1")]));
+    assert!(!is_colorized(&[], &[("CLICOLOR_FORCE", "0")]));
+}
+
+#[test]
+fn clicolor_force_precedence() {
+    assert!(
+        is_colorized(&[], &[("CLICOLOR", "0"), ("CLICOLOR_FORCE", "1")]),
+        "CLICOLOR_FORCE should take precedence over CLICOLOR"
+    );
+    assert!(
+        !is_colorized(&[], &[("NO_COLOR", "1"), ("CLICOLOR_FORCE", "1")]),
+        "NO_COLOR should take precedence over CLICOLOR_FORCE"
+    );
+    assert!(
+        !is_colorized(&["--color", "none"], &[("CLICOLOR_FORCE", "1")]),
+        "'--color none' should take precedence over CLICOLOR_FORCE"
+    );
+}
+
+#[test]
+fn clicolor_off() {
+    assert!(
+        is_colorized(&["--color", "force"], &[("CLICOLOR", "0")]),
+        "'--color force' should take precedence over CLICOLOR"
+    );
+    assert!(!is_colorized(&[], &[("CLICOLOR", "0")]));
+}
+
+#[test]
+fn no_color() {
+    assert!(
+        is_colorized(&["--color", "force"], &[("NO_COLOR", "1")]),
+        "'--color force' should take precedence over NO_COLOR"
+    );
+    assert!(
+        is_colorized(&[], &[("NO_COLOR", ""), ("CLICOLOR_FORCE", "1")]),
+        "An empty NO_COLOR should be ignored"
+    );
+}
@@ -0,0 +1,54 @@
+use indoc::indoc;
+
+mod utils;
+
+#[test]
+fn csv() {
+    assert_eq!(
+        utils::run_cmd(&["--csv", "tests/data"]),
+        indoc!(
+            "path,size_bytes,file_type,depth
+            data,1241,directory,0
+            dream_cycle,308,directory,1
+            dream_cycle/polaris.txt,308,file,2
+            lipsum,446,directory,1
+            lipsum/lipsum.txt,446,file,2
+            necronomicon.txt,83,file,1
+            nemesis.txt,161,file,1
+            nylarlathotep.txt,100,file,1
+            the_yellow_king,143,directory,1
+            the_yellow_king/cassildas_song.md,143,file,2"
+        )
+    )
+}
+
+#[test]
+fn csv_escaped_path() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp = tempfile::TempDir::new()?;
+    let dir = tmp.path().join("escape");
+
+    std::fs::create_dir(&dir)?;
+    std::fs::write(dir.join("a,b.txt"), "abc")?;
+    std::fs::write(dir.join("say \"hi\".txt"), "hi")?;
+
+    let dir = dir.to_string_lossy();
+
+    assert_eq!(
+        utils::run_cmd(&["--csv", &dir]),
+        indoc!(
+            r#"path,size_bytes,file_type,depth
+            escape,5,directory,0
+            "a,b.txt",3,file,1
+            "say ""hi"".txt",2,file,1"#
+        ),
+        "Failed to escape fields as per RFC 4180"
+    );
+
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn csv_conflicts_with_layout() {
+    utils::run_cmd(&["--csv", "--layout", "flat", "tests/data"]);
+}
@@ -0,0 +1,19 @@
+mod utils;
+
+#[test]
+fn depth_first() {
+    for args in [
+        &["tests/data"][..],
+        &["--level", "1", "tests/data"],
+        &["--layout", "inverted", "tests/data"],
+        &["--layout", "flat", "--level", "1", "tests/data"],
+    ] {
+        let streamed = utils::run_cmd(&[&["--depth-first"][..], args].concat());
+
+        assert_eq!(
+            streamed,
+            utils::run_cmd(args),
+            "Streamed output should be identical to buffered output for {args:?}"
+        );
+    }
+}
@@ -0,0 +1,47 @@
+use indoc::indoc;
+use std::{error::Error, fs};
+
+mod utils;
+
+#[test]
+fn erdtreeignore() -> Result<(), Box<dyn Error>> {
+    let tmp = tempfile::TempDir::new()?;
+    let dir = tmp.path().join("proj");
+    let nested = dir.join("nested");
+
+    fs::create_dir_all(&nested)?;
+    fs::write(dir.join("a.txt"), "abc")?;
+    fs::write(dir.join("b.tmp"), "ab")?;
+    fs::write(nested.join("a.txt"), "a")?;
+    fs::write(dir.join(".erdtreeignore"), "*.tmp\n/a.txt\n")?;
+
+    let dir = dir.to_string_lossy();
+
+    assert_eq!(
+        utils::run_cmd(&[&dir]),
+        indoc!(
+            "1 B    ┌─ a.txt
+            1 B ┌─ nested
+            1 B proj
+
+            1 directory, 1 file"
+        ),
+        "Patterns of '.erdtreeignore' should be relative to the root of traversal"
+    );
+
+    assert_eq!(
+        utils::run_cmd(&["--no-ignore", &dir]),
+        indoc!(
+            "1 B    ┌─ a.txt
+            1 B ┌─ nested
+            2 B ├─ b.tmp
+            3 B ├─ a.txt
+            6 B proj
+
+            1 directory, 3 files"
+        ),
+        "'--no-ignore' should disable '.erdtreeignore'"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use indoc::indoc;
+use std::{error::Error, fs, path::Path};
+
+mod utils;
+
+fn fixture(root: &Path) -> Result<(), Box<dyn Error>> {
+    fs::create_dir_all(root.join("src"))?;
+    fs::create_dir_all(root.join("target").join("debug"))?;
+    fs::write(root.join("src").join("main.rs"), "fn main() {}")?;
+    fs::write(root.join("src").join("lib.rs"), "pub fn f() {}")?;
+    fs::write(root.join("target").join("debug").join("out.rs"), "abc")?;
+    fs::write(root.join("notes.txt"), "hello")?;
+    Ok(())
+}
+
+#[test]
+fn exclude() -> Result<(), Box<dyn Error>> {
+    let tmp = tempfile::TempDir::new()?;
+    let dir = tmp.path().join("proj");
+    fixture(&dir)?;
+
+    let dir = dir.to_string_lossy();
+
+    assert_eq!(
+        utils::run_cmd(&["--exclude", "target/", &dir]),
+        indoc!(
+            "12 B    ┌─ main.rs
+            13 B    ├─ lib.rs
+            25 B ┌─ src
+             5 B ├─ notes.txt
+            30 B proj
+
+            1 directory, 3 files"
+        ),
+        "Failed to exclude directory"
+    );
+
+    assert_eq!(
+        utils::run_cmd(&["--exclude", "target/", "--exclude", "*.txt", &dir]),
+        indoc!(
+            "12 B    ┌─ main.rs
+            13 B    ├─ lib.rs
+            25 B ┌─ src
+            25 B proj
+
+            1 directory, 2 files"
+        ),
+        "Failed to apply multiple exclusions"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn exclude_with_pattern() -> Result<(), Box<dyn Error>> {
+    let tmp = tempfile::TempDir::new()?;
+    let dir = tmp.path().join("proj");
+    fixture(&dir)?;
+
+    let dir = dir.to_string_lossy();
+
+    assert_eq!(
+        utils::run_cmd(&["--glob", "--pattern", "*.rs", "--exclude", "target/", &dir]),
+        indoc!(
+            "12 B    ┌─ main.rs
+            13 B    ├─ lib.rs
+            25 B ┌─ src
+            25 B proj
+
+            1 directory, 2 files"
+        ),
+        "Exclusions should compose with an inclusion pattern"
+    );
+
+    Ok(())
+}
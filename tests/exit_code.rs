@@ -0,0 +1,39 @@
+mod utils;
+
+fn exit_code(args: &[&str]) -> Option<i32> {
+    utils::run_cmd_raw(args, &[]).status.code()
+}
+
+#[test]
+fn exit_code_no_matches() {
+    assert_eq!(exit_code(&["--pattern", "weewoo", "tests/data"]), Some(1));
+    assert_eq!(
+        exit_code(&["--glob", "--pattern", "*weewoo*", "tests/data"]),
+        Some(1)
+    );
+}
+
+#[test]
+fn exit_code_error() {
+    assert_eq!(exit_code(&["--pattern", "*.txt", "tests/data"]), Some(2));
+}
+
+#[test]
+fn exit_code_success() {
+    assert_eq!(exit_code(&["tests/data"]), Some(0));
+    assert_eq!(exit_code(&["--pattern", "nemesis", "tests/data"]), Some(0));
+}
+
+#[test]
+fn exit_code_empty_dir() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp = tempfile::TempDir::new()?;
+    let empty = tmp.path().to_string_lossy();
+
+    assert_eq!(
+        exit_code(&[&empty]),
+        Some(0),
+        "Empty directories without a search shouldn't be an error"
+    );
+
+    Ok(())
+}
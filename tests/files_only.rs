@@ -0,0 +1,67 @@
+mod utils;
+
+#[cfg(unix)]
+mod test {
+    use indoc::indoc;
+    use std::{error::Error, fs, os::unix::fs::symlink, path::PathBuf};
+    use tempfile::TempDir;
+
+    /// Creates a directory containing regular files, a symlink, and an empty directory.
+    fn fixture(tmp: &TempDir) -> Result<PathBuf, Box<dyn Error>> {
+        let root = tmp.path().join("root");
+
+        fs::create_dir_all(root.join("empty"))?;
+        fs::create_dir_all(root.join("links"))?;
+        fs::create_dir_all(root.join("sub"))?;
+        fs::write(root.join("a.txt"), "aa")?;
+        fs::write(root.join("sub").join("b.txt"), "b")?;
+        symlink("../a.txt", root.join("links").join("link"))?;
+
+        Ok(root)
+    }
+
+    #[test]
+    fn files_only() -> Result<(), Box<dyn Error>> {
+        let tmp = TempDir::new()?;
+        let root = fixture(&tmp)?;
+
+        assert_eq!(
+            super::utils::run_cmd(&["--files-only", "--suppress-size", &root.to_string_lossy()]),
+            indoc!(
+                "┌─ b.txt
+                 ┌─ sub
+                 ├─ a.txt
+                 root
+
+                1 directory, 2 files"
+            ),
+            "Only regular files and the directories containing them should remain"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn files_only_follow() -> Result<(), Box<dyn Error>> {
+        let tmp = TempDir::new()?;
+        let root = fixture(&tmp)?;
+
+        let out = super::utils::run_cmd(&[
+            "--files-only",
+            "--follow",
+            "--suppress-size",
+            &root.to_string_lossy(),
+        ]);
+
+        assert!(
+            out.contains("links"),
+            "Followed symlinks that resolve to files should be kept:\n{out}"
+        );
+        assert!(
+            !out.contains("empty"),
+            "Directories without files should be pruned:\n{out}"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+use indoc::indoc;
+
+mod utils;
+
+/// Width of the disk usage column of every row, not counting the unit. The first row is skipped
+/// as its leading whitespace is trimmed by [`utils::run_cmd`].
+fn size_widths(out: &str) -> Vec<usize> {
+    out.lines()
+        .skip(1)
+        .take_while(|line| !line.is_empty())
+        .filter_map(|line| line.find(" B "))
+        .collect()
+}
+
+#[test]
+fn fixed_size_width() {
+    let data = utils::run_cmd(&["--fixed-size-width", "6", "tests/data"]);
+    let dream_cycle = utils::run_cmd(&["--fixed-size-width", "6", "tests/data/dream_cycle"]);
+
+    for out in [&data, &dream_cycle] {
+        let widths = size_widths(out);
+        assert!(!widths.is_empty());
+        assert!(
+            widths.iter().all(|width| *width == 6),
+            "Size column should be pinned to the fixed width:\n{out}"
+        );
+    }
+
+    assert_eq!(
+        dream_cycle,
+        indoc!(
+            "308 B ┌─ polaris.txt
+               308 B dream_cycle
+
+            1 file"
+        )
+    );
+}
+
+#[test]
+fn fixed_size_width_overflow() {
+    assert_eq!(
+        utils::run_cmd(&["--fixed-size-width", "2", "tests/data/dream_cycle"]),
+        indoc!(
+            ">9 B ┌─ polaris.txt
+            >9 B dream_cycle
+
+            1 file"
+        ),
+        "Sizes that don't fit should be marked as such rather than losing digits"
+    );
+}
+
+#[test]
+fn fixed_size_width_rescales() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp = tempfile::TempDir::new()?;
+    let root = tmp.path().join("rescale");
+    std::fs::create_dir(&root)?;
+    std::fs::write(root.join("kib.txt"), [b'a'; 2000])?;
+    std::fs::write(root.join("b.txt"), [b'a'; 1000])?;
+
+    assert_eq!(
+        utils::run_cmd(&[
+            "--human",
+            "--scale",
+            "3",
+            "--fixed-size-width",
+            "3",
+            &root.to_string_lossy()
+        ]),
+        indoc!(
+            "2.0 KiB ┌─ kib.txt
+            1.0 KiB ├─ b.txt
+            2.9 KiB rescale
+
+            2 files"
+        ),
+        "Sizes that don't fit should lose decimal places or move to a larger unit"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn unpinned_size_width_zero() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp = tempfile::TempDir::new()?;
+    let root = tmp.path().join("empty");
+    std::fs::create_dir(&root)?;
+    std::fs::File::create(root.join("empty.txt"))?;
+
+    assert_eq!(
+        utils::run_cmd(&[&root.to_string_lossy()]),
+        indoc!(
+            "0 B ┌─ empty.txt
+             empty
+
+            1 file"
+        ),
+        "Sizes of zero shouldn't be truncated when the width isn't pinned"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use indoc::indoc;
+
+mod utils;
+
+#[test]
+fn fixed_unit() {
+    assert_eq!(
+        utils::run_cmd(&["--layout", "flat", "--fixed-unit", "KiB", "tests/data"]),
+        indoc!(
+            "0.1 KiB   the_yellow_king/cassildas_song.md
+0.1 KiB   the_yellow_king
+0.1 KiB   nylarlathotep.txt
+0.2 KiB   nemesis.txt
+0.1 KiB   necronomicon.txt
+0.4 KiB   lipsum/lipsum.txt
+0.4 KiB   lipsum
+0.3 KiB   dream_cycle/polaris.txt
+0.3 KiB   dream_cycle
+1.2 KiB   data
+
+3 directories, 6 files"
+        ),
+        "Expected every size to be reported in KiB"
+    )
+}
+
+#[test]
+fn fixed_unit_bytes() {
+    assert_eq!(
+        utils::run_cmd(&[
+            "--layout",
+            "flat",
+            "--fixed-unit",
+            "B",
+            "--level",
+            "1",
+            "tests/data"
+        ]),
+        indoc!(
+            "143   B   the_yellow_king
+ 100   B   nylarlathotep.txt
+ 161   B   nemesis.txt
+  83   B   necronomicon.txt
+ 446   B   lipsum
+ 308   B   dream_cycle
+1241   B   data
+
+3 directories, 6 files"
+        ),
+        "Expected sizes to stay in bytes even when they exceed a KiB"
+    )
+}
@@ -61,3 +61,30 @@ fn flat_with_level() {
         )
     )
 }
+
+#[test]
+fn flat_human_auto_unit_column() {
+    assert_eq!(
+        utils::run_cmd(&[
+            "--layout",
+            "flat",
+            "--human",
+            "--auto-unit-column",
+            "tests/data"
+        ]),
+        indoc!(
+            "0.1 KiB   the_yellow_king/cassildas_song.md
+            0.1 KiB   the_yellow_king
+            0.1 KiB   nylarlathotep.txt
+            0.2 KiB   nemesis.txt
+            0.1 KiB   necronomicon.txt
+            0.4 KiB   lipsum/lipsum.txt
+            0.4 KiB   lipsum
+            0.3 KiB   dream_cycle/polaris.txt
+            0.3 KiB   dream_cycle
+            1.2 KiB   data
+
+            3 directories, 6 files"
+        )
+    )
+}
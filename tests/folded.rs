@@ -0,0 +1,25 @@
+use indoc::indoc;
+
+mod utils;
+
+#[test]
+fn folded() {
+    assert_eq!(
+        utils::run_cmd(&["--folded", "tests/data"]),
+        indoc!(
+            "data;dream_cycle;polaris.txt 308
+            data;lipsum;lipsum.txt 446
+            data;necronomicon.txt 83
+            data;nemesis.txt 161
+            data;nylarlathotep.txt 100
+            data;the_yellow_king;cassildas_song.md 143"
+        ),
+        "Failed to print folded stacks"
+    )
+}
+
+#[test]
+#[should_panic]
+fn folded_conflicts_with_csv() {
+    utils::run_cmd(&["--folded", "--csv", "tests/data"]);
+}
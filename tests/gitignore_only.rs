@@ -0,0 +1,46 @@
+use indoc::indoc;
+use std::{error::Error, fs, path::Path};
+
+mod utils;
+
+fn fixture(root: &Path) -> Result<(), Box<dyn Error>> {
+    fs::create_dir_all(root.join(".git"))?;
+    fs::create_dir_all(root.join("empty"))?;
+    fs::create_dir_all(root.join("logs"))?;
+    fs::write(root.join(".git").join("HEAD"), "ref: refs/heads/main\n")?;
+    fs::write(root.join(".gitignore"), "*.log\n")?;
+    fs::write(root.join(".ignore"), "b.txt\n")?;
+    fs::write(root.join(".hidden"), "h")?;
+    fs::write(root.join("b.txt"), "b")?;
+    fs::write(root.join("logs").join("a.log"), "a")?;
+    Ok(())
+}
+
+#[test]
+fn gitignore_only() -> Result<(), Box<dyn Error>> {
+    let tmp = tempfile::TempDir::new()?;
+    let dir = tmp.path().join("repo");
+    fixture(&dir)?;
+
+    assert_eq!(
+        utils::run_cmd(&["--gitignore-only", &dir.to_string_lossy()]),
+        indoc!(
+            "1 B ┌─ b.txt
+             6 B ├─ .ignore
+             1 B ├─ .hidden
+             6 B ├─ .gitignore
+            14 B repo
+
+            4 files"
+        ),
+        "Only files that git would track should be shown"
+    );
+
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn gitignore_only_conflicts_with_no_ignore() {
+    utils::run_cmd(&["--gitignore-only", "--no-ignore", "tests/data"]);
+}
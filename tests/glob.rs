@@ -119,3 +119,114 @@ fn glob_empty_set_dir() {
 fn glob_empty_set_file() {
     utils::run_cmd(&["--glob", "--pattern", "*weewoo*", "tests/data"]);
 }
+
+#[test]
+fn glob_brace_expansion() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp = tempfile::TempDir::new()?;
+    let dir = tmp.path().join("braces");
+
+    std::fs::create_dir(&dir)?;
+    std::fs::write(dir.join("build.rs"), "fn main() {}")?;
+    std::fs::write(dir.join("cargo.toml"), "[package]")?;
+    std::fs::write(dir.join("notes.md"), "# Notes")?;
+
+    let dir = dir.to_string_lossy();
+
+    assert_eq!(
+        utils::run_cmd(&["--glob", "--pattern", "*.{rs,toml}", &dir]),
+        indoc!(
+            "9 B ┌─ cargo.toml
+            12 B ├─ build.rs
+            21 B braces
+
+            2 files"
+        ),
+        "Failed to expand brace alternation"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn glob_escaped_brace() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp = tempfile::TempDir::new()?;
+    let dir = tmp.path().join("literal");
+
+    std::fs::create_dir(&dir)?;
+    std::fs::write(dir.join("{a,b}.txt"), "abc")?;
+    std::fs::write(dir.join("a.txt"), "abc")?;
+    std::fs::write(dir.join("b.txt"), "abc")?;
+
+    let dir = dir.to_string_lossy();
+
+    assert_eq!(
+        utils::run_cmd(&["--glob", "--pattern", r"\{a,b\}.txt", &dir]),
+        indoc!(
+            "3 B ┌─ {a,b}.txt
+            3 B literal
+
+            1 file"
+        ),
+        "Escaped braces should be matched literally"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn glob_full_path() {
+    assert_eq!(
+        utils::run_cmd(&["--glob", "--full-path", "--pattern", "*.txt", "tests/data"]),
+        indoc!(
+            "100 B ┌─ nylarlathotep.txt
+             161 B ├─ nemesis.txt
+              83 B ├─ necronomicon.txt
+             344 B data
+
+             3 files"
+        ),
+        "Globs matched against the full path should be anchored to the root"
+    );
+
+    assert_eq!(
+        utils::run_cmd(&[
+            "--glob",
+            "--full-path",
+            "--pattern",
+            "*/*.txt",
+            "tests/data"
+        ]),
+        indoc!(
+            "446 B    ┌─ lipsum.txt
+             446 B ┌─ lipsum
+             308 B │  ┌─ polaris.txt
+             308 B ├─ dream_cycle
+             754 B data
+
+             2 directories, 2 files"
+        )
+    );
+}
+
+#[test]
+fn glob_full_path_dir() {
+    assert_eq!(
+        utils::run_cmd(&[
+            "--glob",
+            "--full-path",
+            "--pattern",
+            "dream_cycle",
+            "--file-type",
+            "dir",
+            "tests/data"
+        ]),
+        indoc!(
+            "308 B    ┌─ polaris.txt
+             308 B ┌─ dream_cycle
+             308 B data
+
+             1 directory, 1 file"
+        ),
+        "Contents of directories whose path matches should be included"
+    );
+}
@@ -0,0 +1,56 @@
+use indoc::indoc;
+
+mod utils;
+
+#[test]
+fn hide_clutter() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp = tempfile::TempDir::new()?;
+    let dir = tmp.path().join("clutter");
+
+    std::fs::create_dir(&dir)?;
+    std::fs::write(dir.join(".DS_Store"), "abc")?;
+    std::fs::write(dir.join("foo.swp"), "abcd")?;
+    std::fs::write(dir.join("keep.txt"), "hello")?;
+    std::fs::write(dir.join("notes.bak"), "ab")?;
+
+    let dir = dir.to_string_lossy();
+
+    assert_eq!(
+        utils::run_cmd(&["--hidden", &dir]),
+        indoc!(
+            "2 B ┌─ notes.bak
+             5 B ├─ keep.txt
+             4 B ├─ foo.swp
+             3 B ├─ .DS_Store
+            14 B clutter
+
+            4 files"
+        ),
+        "Clutter should be visible by default"
+    );
+
+    assert_eq!(
+        utils::run_cmd(&["--hidden", "--hide-clutter", &dir]),
+        indoc!(
+            "2 B ┌─ notes.bak
+            5 B ├─ keep.txt
+            7 B clutter
+
+            2 files"
+        ),
+        "Failed to hide built-in clutter"
+    );
+
+    assert_eq!(
+        utils::run_cmd(&["--hidden", "--hide-clutter", "--clutter", "*.bak", &dir]),
+        indoc!(
+            "5 B ┌─ keep.txt
+            5 B clutter
+
+            1 file"
+        ),
+        "Failed to hide user-provided clutter"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+mod utils;
+
+/// ANSI escape sequence of the bold orange used to highlight deeply nested entries.
+const WARNING: &str = "\u{1b}[1;38;2;255;140;0m";
+
+#[test]
+fn highlight_depth() -> Result<(), Box<dyn std::error::Error>> {
+    let output = utils::run_cmd_raw(
+        &["--color", "force", "--highlight-depth", "2", "tests/data"],
+        &[("LS_COLORS", "")],
+    );
+
+    assert!(output.status.success());
+
+    let stdout = output.stdout;
+
+    let line_for = |name: &str| {
+        stdout
+            .lines()
+            .find(|line| line.contains(name))
+            .unwrap_or_else(|| panic!("Expected '{name}' in output"))
+    };
+
+    for name in ["cassildas_song.md", "lipsum.txt", "polaris.txt"] {
+        assert!(
+            line_for(name).contains(WARNING),
+            "Expected '{name}' at depth 2 to be highlighted"
+        );
+    }
+
+    for name in ["the_yellow_king", "nemesis.txt", "dream_cycle"] {
+        assert!(
+            !line_for(name).contains(WARNING),
+            "Expected '{name}' at depth 1 to use normal styling"
+        );
+    }
+
+    Ok(())
+}
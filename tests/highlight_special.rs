@@ -0,0 +1,65 @@
+mod utils;
+
+#[cfg(unix)]
+mod test {
+    use super::utils;
+    use std::{
+        fs::{self, Permissions},
+        os::unix::fs::PermissionsExt,
+    };
+
+    /// ANSI escape sequence of the bold white on red used to highlight files with special bits.
+    const SPECIAL: &str = "\u{1b}[1;41;37m";
+
+    #[test]
+    fn highlight_special() -> Result<(), Box<dyn std::error::Error>> {
+        let tmp = tempfile::TempDir::new()?;
+
+        let setuid = tmp.path().join("setuid");
+        fs::write(&setuid, "")?;
+        fs::set_permissions(&setuid, Permissions::from_mode(0o4755))?;
+
+        let sticky = tmp.path().join("sticky");
+        fs::create_dir(&sticky)?;
+        fs::set_permissions(&sticky, Permissions::from_mode(0o1777))?;
+
+        let plain = tmp.path().join("plain");
+        fs::write(&plain, "")?;
+        fs::set_permissions(&plain, Permissions::from_mode(0o755))?;
+
+        let output = utils::run_cmd_raw(
+            &[
+                "--color",
+                "force",
+                "--highlight-special",
+                &tmp.path().to_string_lossy(),
+            ],
+            &[("LS_COLORS", "")],
+        );
+
+        assert!(output.status.success());
+
+        let stdout = output.stdout;
+
+        let line_for = |name: &str| {
+            stdout
+                .lines()
+                .find(|line| line.contains(name))
+                .unwrap_or_else(|| panic!("Expected '{name}' in output"))
+        };
+
+        for name in ["setuid", "sticky"] {
+            assert!(
+                line_for(name).contains(SPECIAL),
+                "Expected '{name}' to be highlighted"
+            );
+        }
+
+        assert!(
+            !line_for("plain").contains(SPECIAL),
+            "Expected 'plain' to use normal styling"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+mod utils;
+
+#[test]
+fn emoji_icons() {
+    let out = utils::run_cmd(&[
+        "--icons",
+        "--icon-theme",
+        "emoji",
+        "tests/data/the_yellow_king",
+    ]);
+
+    assert_eq!(
+        out,
+        indoc::indoc!(
+            "143 B ┌─ 📝 cassildas_song.md
+            143 B 📁 the_yellow_king
+
+            1 file"
+        )
+    );
+}
+
+#[cfg(unix)]
+mod test {
+    use super::utils;
+    use std::{error::Error, fs, os::unix::net::UnixListener, process::Command};
+
+    /// Returns the icon preceding `file_name` in `out`.
+    fn icon_of<'a>(out: &'a str, file_name: &str) -> &'a str {
+        out.lines()
+            .find_map(|line| line.strip_suffix(&format!(" {file_name}")))
+            .and_then(|rest| rest.split_whitespace().last())
+            .unwrap_or_else(|| panic!("Missing {file_name} in output:\n{out}"))
+    }
+
+    #[test]
+    fn special_file_type_icons() -> Result<(), Box<dyn Error>> {
+        let tmp = tempfile::TempDir::new()?;
+        let dir = tmp.path().join("special");
+        fs::create_dir(&dir)?;
+        fs::write(dir.join("file.txt"), "abc")?;
+
+        let status = Command::new("mkfifo").arg(dir.join("pipe")).status()?;
+        assert!(status.success(), "Failed to create fifo fixture");
+
+        let _listener = UnixListener::bind(dir.join("sock"))?;
+
+        let out = utils::run_cmd(&["--icons", &dir.to_string_lossy()]);
+
+        assert_eq!(
+            icon_of(&out, "pipe"),
+            "\u{f0ec}",
+            "Unexpected icon for fifo"
+        );
+        assert_eq!(
+            icon_of(&out, "sock"),
+            "\u{f1e6}",
+            "Unexpected icon for socket"
+        );
+        assert_ne!(
+            icon_of(&out, "file.txt"),
+            icon_of(&out, "pipe"),
+            "Fifo should not share the icon of a regular file"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn full_file_name_icons() -> Result<(), Box<dyn Error>> {
+        let tmp = tempfile::TempDir::new()?;
+        let dir = tmp.path().join("names");
+        fs::create_dir(&dir)?;
+        fs::write(dir.join("compose.yml"), "")?;
+        fs::write(dir.join("docker-compose.override.yml"), "")?;
+        fs::write(dir.join(".eslintrc.json"), "")?;
+
+        let out = utils::run_cmd(&["--icons", "--hidden", &dir.to_string_lossy()]);
+
+        assert_eq!(
+            icon_of(&out, "docker-compose.override.yml"),
+            "\u{f308}",
+            "Complete file name should take precedence over the extension"
+        );
+        assert_eq!(icon_of(&out, ".eslintrc.json"), "\u{e655}");
+        assert_eq!(
+            icon_of(&out, "compose.yml"),
+            "\u{e615}",
+            "Extension should be used when the complete file name isn't known"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+use indoc::indoc;
+use std::{error::Error, fs};
+
+mod utils;
+
+#[test]
+fn ignore_file() -> Result<(), Box<dyn Error>> {
+    let tmp = tempfile::TempDir::new()?;
+    let dir = tmp.path().join("proj");
+    let nested = dir.join("nested");
+
+    fs::create_dir_all(&nested)?;
+    fs::write(dir.join("a.txt"), "abc")?;
+    fs::write(dir.join("b.tmp"), "ab")?;
+    fs::write(nested.join("a.txt"), "a")?;
+
+    let ignore_file = tmp.path().join(".fdignore");
+    fs::write(&ignore_file, "*.tmp\n/proj/a.txt\n")?;
+
+    let dir = dir.to_string_lossy();
+    let ignore_file = ignore_file.to_string_lossy();
+
+    assert_eq!(
+        utils::run_cmd(&["--ignore-file", &ignore_file, &dir]),
+        indoc!(
+            "1 B    ┌─ a.txt
+            1 B ┌─ nested
+            1 B proj
+
+            1 directory, 1 file"
+        ),
+        "Patterns should be relative to the directory of the ignore file"
+    );
+
+    assert_eq!(
+        utils::run_cmd(&["--ignore-file", &ignore_file, "--no-ignore", &dir]),
+        indoc!(
+            "1 B    ┌─ a.txt
+            1 B ┌─ nested
+            2 B ├─ b.tmp
+            3 B ├─ a.txt
+            6 B proj
+
+            1 directory, 3 files"
+        ),
+        "'--no-ignore' should disable ignore files"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+mod utils;
+
+/// Tabs would be lost to the stripping of ANSI escapes done by [`utils::run_cmd`].
+fn run_indent(args: &[&str]) -> String {
+    let output = utils::run_cmd_raw(args, &[]);
+    assert!(output.status.success(), "{}", output.stderr);
+    output.stdout.trim_end().to_string()
+}
+
+#[test]
+fn indent() {
+    assert_eq!(
+        run_indent(&["--indent", "tests/data"]),
+        [
+            "data\t1241",
+            "\tdream_cycle\t308",
+            "\t\tpolaris.txt\t308",
+            "\tlipsum\t446",
+            "\t\tlipsum.txt\t446",
+            "\tnecronomicon.txt\t83",
+            "\tnemesis.txt\t161",
+            "\tnylarlathotep.txt\t100",
+            "\tthe_yellow_king\t143",
+            "\t\tcassildas_song.md\t143",
+        ]
+        .join("\n"),
+        "Failed to print indented tree"
+    )
+}
+
+#[test]
+fn indent_depth() {
+    let out = run_indent(&["--indent", "--suppress-size", "tests/data"]);
+
+    for line in out.lines() {
+        let name = line.trim_start_matches('\t');
+        let tabs = line.len() - name.len();
+
+        let expected_depth = match name {
+            "data" => 0,
+            "dream_cycle" | "lipsum" | "the_yellow_king" => 1,
+            "necronomicon.txt" | "nemesis.txt" | "nylarlathotep.txt" => 1,
+            "polaris.txt" | "lipsum.txt" | "cassildas_song.md" => 2,
+            _ => panic!("Unexpected entry '{name}'"),
+        };
+
+        assert_eq!(tabs, expected_depth, "Wrong indentation for '{name}'");
+    }
+}
+
+#[test]
+#[should_panic]
+fn indent_conflicts_with_csv() {
+    utils::run_cmd(&["--indent", "--csv", "tests/data"]);
+}
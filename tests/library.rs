@@ -0,0 +1,42 @@
+use clap::Parser;
+use erdtree::Context;
+use std::ffi::OsStr;
+
+#[test]
+fn build_tree() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = Context::try_parse_from([
+        "erd",
+        "--disk-usage",
+        "logical",
+        "--sort",
+        "name",
+        "--no-config",
+        "tests/data",
+    ])?;
+
+    let tree = erdtree::build_tree(&ctx)?;
+
+    assert_eq!(tree.root().file_name(), OsStr::new("data"));
+    assert_eq!(tree.root().file_size().map(|size| size.value()), Some(1241));
+
+    let mut children = tree
+        .children(tree.root_id())
+        .map(|node| node.file_name().to_string_lossy().into_owned())
+        .collect::<Vec<_>>();
+
+    children.sort();
+
+    assert_eq!(
+        children,
+        [
+            "dream_cycle",
+            "lipsum",
+            "necronomicon.txt",
+            "nemesis.txt",
+            "nylarlathotep.txt",
+            "the_yellow_king"
+        ]
+    );
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use indoc::indoc;
+use std::{error::Error, fs};
 
 mod utils;
 
@@ -22,3 +23,26 @@ fn line_count() {
         )
     )
 }
+
+#[test]
+fn line_count_binary() -> Result<(), Box<dyn Error>> {
+    let tmp = tempfile::TempDir::new()?;
+    let dir = tmp.path().join("bin");
+    fs::create_dir(&dir)?;
+    fs::write(dir.join("blob.bin"), [0xff, 0xfe, 0x00, 0x01])?;
+    fs::write(dir.join("data.bin"), [0xff, b'\n', 0xfe, b'\n'])?;
+
+    assert_eq!(
+        utils::run_cmd(&["--disk-usage", "line", &dir.to_string_lossy()]),
+        indoc!(
+            "2 ┌─ data.bin
+            1 ├─ blob.bin
+            3 bin
+
+            2 files"
+        ),
+        "Failed to count lines of non-UTF-8 files"
+    );
+
+    Ok(())
+}
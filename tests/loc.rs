@@ -0,0 +1,29 @@
+use indoc::indoc;
+use std::{error::Error, fs};
+
+mod utils;
+
+#[test]
+fn loc() -> Result<(), Box<dyn Error>> {
+    let tmp = tempfile::TempDir::new()?;
+    let dir = tmp.path().join("proj");
+    fs::create_dir(&dir)?;
+    fs::write(dir.join("main.rs"), "fn main() {\n    lib::f();\n}\n")?;
+    fs::write(dir.join("lib.rs"), "pub fn f() {\n}\n")?;
+    fs::write(dir.join("logo.png"), "\n\n\n\n\n\n\n\n")?;
+
+    assert_eq!(
+        utils::run_cmd(&["--loc", &dir.to_string_lossy()]),
+        indoc!(
+            "3 ┌─ main.rs
+            0 ├─ logo.png
+            2 ├─ lib.rs
+            5 proj
+
+            3 files"
+        ),
+        "Only lines of source files should be counted"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use indoc::indoc;
+use std::fs;
+
+mod utils;
+
+#[test]
+fn markdown() {
+    assert_eq!(
+        utils::run_cmd(&["--markdown", "tests/data"]),
+        indoc!(
+            "
+            - **data** (1241 B)
+              - **dream\\_cycle** (308 B)
+                - polaris.txt (308 B)
+              - **lipsum** (446 B)
+                - lipsum.txt (446 B)
+              - necronomicon.txt (83 B)
+              - nemesis.txt (161 B)
+              - nylarlathotep.txt (100 B)
+              - **the\\_yellow\\_king** (143 B)
+                - cassildas\\_song.md (143 B)"
+        ),
+        "Failed to print Markdown list"
+    )
+}
+
+#[test]
+fn markdown_level_dirs_only() {
+    assert_eq!(
+        utils::run_cmd(&[
+            "--markdown",
+            "--level",
+            "1",
+            "--dirs-only",
+            "--suppress-size",
+            "tests/data"
+        ]),
+        indoc!(
+            "
+            - **data**
+              - **dream\\_cycle**
+              - **lipsum**
+              - **the\\_yellow\\_king**"
+        ),
+        "'--markdown' should honor '--level' and '--dirs-only'"
+    )
+}
+
+#[test]
+fn markdown_escapes_file_names() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp = tempfile::TempDir::new()?;
+    let root = tmp.path().join("pkg");
+    fs::create_dir(&root)?;
+
+    for name in ["__init__.py", "#notes#", "<tmp>", "1. intro.md", "-rf"] {
+        fs::write(root.join(name), "")?;
+    }
+
+    assert_eq!(
+        utils::run_cmd(&["--markdown", "--suppress-size", &root.to_string_lossy()]),
+        indoc!(
+            "
+            - **pkg**
+              - \\#notes\\#
+              - \\-rf
+              - 1\\. intro.md
+              - \\<tmp\\>
+              - \\_\\_init\\_\\_.py"
+        ),
+        "Markdown syntax in file names should be escaped"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn markdown_no_color() {
+    let out = utils::run_cmd(&["--markdown", "--color", "force", "tests/data"]);
+
+    assert!(
+        !out.contains('\u{1b}'),
+        "'--markdown' shouldn't emit ANSI escapes"
+    );
+}
+
+#[test]
+#[should_panic]
+fn markdown_conflicts_with_csv() {
+    utils::run_cmd(&["--markdown", "--csv", "tests/data"]);
+}
@@ -0,0 +1,42 @@
+use indoc::formatdoc;
+use std::fs;
+
+mod utils;
+
+/// The root is canonicalized before traversal so the deepest entry is reported by its absolute
+/// path.
+fn deepest() -> String {
+    let path = fs::canonicalize("tests/data/dream_cycle/polaris.txt").unwrap();
+    format!("max depth reached: 2 at {}", path.display())
+}
+
+#[test]
+fn max_depth_stat() {
+    assert_eq!(
+        utils::run_cmd(&["--max-depth-stat", "tests/data"]),
+        formatdoc!(
+            "143 B    ┌─ cassildas_song.md
+ 143 B ┌─ the_yellow_king
+ 100 B ├─ nylarlathotep.txt
+ 161 B ├─ nemesis.txt
+  83 B ├─ necronomicon.txt
+ 446 B │  ┌─ lipsum.txt
+ 446 B ├─ lipsum
+ 308 B │  ┌─ polaris.txt
+ 308 B ├─ dream_cycle
+1241 B data
+
+3 directories, 6 files
+{}",
+            deepest()
+        ),
+        "Failed to report the deepest entry"
+    )
+}
+
+#[test]
+fn max_depth_stat_flat() {
+    let out = utils::run_cmd(&["--max-depth-stat", "--layout", "flat", "tests/data"]);
+
+    assert_eq!(out.lines().last(), Some(deepest().as_str()));
+}
@@ -0,0 +1,89 @@
+use indoc::indoc;
+
+mod utils;
+
+#[test]
+fn max_entries_largest() {
+    assert_eq!(
+        utils::run_cmd(&["--sort", "size", "--max-entries", "2", "tests/data"]),
+        indoc!(
+            "487 B ┌─ … and 4 more
+ 308 B │  ┌─ polaris.txt
+ 308 B ├─ dream_cycle
+ 446 B │  ┌─ lipsum.txt
+ 446 B ├─ lipsum
+1241 B data
+
+3 directories, 6 files"
+        ),
+        "Expected the two largest entries to be kept with the rest summarized"
+    )
+}
+
+#[test]
+fn max_entries_truncated_directory() {
+    assert_eq!(
+        utils::run_cmd(&["--sort", "name", "--max-entries", "1", "tests/data"]),
+        indoc!(
+            "933 B ┌─ … and 5 more
+ 308 B │  ┌─ polaris.txt
+ 308 B ├─ dream_cycle
+1241 B data
+
+3 directories, 6 files"
+        ),
+        "Contents of truncated directories should not be shown"
+    )
+}
+
+#[test]
+fn max_entries_not_exceeded() {
+    assert_eq!(
+        utils::run_cmd(&["--sort", "name", "--max-entries", "6", "tests/data"]),
+        utils::run_cmd(&["--sort", "name", "tests/data"]),
+    )
+}
+
+#[cfg(unix)]
+#[test]
+fn max_entries_long() {
+    for perms in [&[][..], &["--octal"][..]] {
+        let mut args = vec!["--long", "--sort", "name", "--max-entries", "1"];
+        args.extend(perms);
+        args.push("tests/data");
+
+        let out = utils::run_cmd(&args);
+
+        let placeholder = out
+            .lines()
+            .find(|line| line.ends_with("┌─ … and 5 more"))
+            .unwrap_or_else(|| panic!("Expected a placeholder in:\n{out}"));
+
+        assert!(
+            placeholder.starts_with("- "),
+            "Placeholder should have no permissions of its own:\n{out}"
+        );
+    }
+}
+
+#[test]
+fn max_entries_with_summary() {
+    let out = utils::run_cmd(&[
+        "--sort",
+        "name",
+        "--max-entries",
+        "1",
+        "--with-summary",
+        "3",
+        "tests/data",
+    ]);
+
+    let (_, summary) = out
+        .split_once("largest entries:")
+        .unwrap_or_else(|| panic!("Expected a summary in:\n{out}"));
+
+    assert!(
+        !summary.contains("more"),
+        "Placeholders shouldn't be listed among the largest entries:\n{out}"
+    );
+}
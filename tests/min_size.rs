@@ -0,0 +1,74 @@
+use indoc::indoc;
+
+mod utils;
+
+#[test]
+fn min_size() {
+    assert_eq!(
+        utils::run_cmd(&["--min-size", "150", "tests/data"]),
+        indoc!(
+            "161 B ┌─ nemesis.txt
+            446 B │  ┌─ lipsum.txt
+            446 B ├─ lipsum
+            308 B │  ┌─ polaris.txt
+            308 B ├─ dream_cycle
+            915 B data
+
+            2 directories, 3 files"
+        ),
+        "Failed to omit files below the minimum size"
+    )
+}
+
+#[test]
+fn min_size_human() {
+    assert_eq!(
+        utils::run_cmd(&["--min-size", "0.3K", "tests/data"]),
+        indoc!(
+            "446 B    ┌─ lipsum.txt
+            446 B ┌─ lipsum
+            308 B │  ┌─ polaris.txt
+            308 B ├─ dream_cycle
+            754 B data
+
+            2 directories, 2 files"
+        ),
+        "Failed to parse human-readable minimum size"
+    )
+}
+
+#[test]
+#[should_panic]
+fn min_size_invalid() {
+    utils::run_cmd(&["--min-size", "10X", "tests/data"]);
+}
+
+#[test]
+#[should_panic]
+fn min_size_suppress_size() {
+    utils::run_cmd(&["--min-size", "143", "--suppress-size", "tests/data"]);
+}
+
+#[test]
+#[should_panic]
+fn max_size_suppress_size() {
+    utils::run_cmd(&["--max-size", "308", "--suppress-size", "tests/data"]);
+}
+
+#[test]
+fn size_band() {
+    assert_eq!(
+        utils::run_cmd(&["--min-size", "143", "--max-size", "308", "tests/data"]),
+        indoc!(
+            "143 B    ┌─ cassildas_song.md
+            143 B ┌─ the_yellow_king
+            161 B ├─ nemesis.txt
+            308 B │  ┌─ polaris.txt
+            308 B ├─ dream_cycle
+            612 B data
+
+            2 directories, 3 files"
+        ),
+        "Files exactly at either bound should be retained"
+    )
+}
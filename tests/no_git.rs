@@ -0,0 +1,53 @@
+use indoc::indoc;
+use std::{error::Error, fs, path::Path};
+
+mod utils;
+
+fn fixture(root: &Path) -> Result<(), Box<dyn Error>> {
+    fs::create_dir_all(root.join(".git"))?;
+    fs::write(root.join(".git").join("HEAD"), "ref: refs/heads/main\n")?;
+    fs::write(root.join(".gitignore"), "*.log\n!keep.log\n")?;
+    fs::write(root.join("a.log"), "a")?;
+    fs::write(root.join("keep.log"), "keep")?;
+    fs::write(root.join("b.txt"), "b")?;
+    Ok(())
+}
+
+#[test]
+fn no_git_with_gitignore_negation() -> Result<(), Box<dyn Error>> {
+    let tmp = tempfile::TempDir::new()?;
+    let dir = tmp.path().join("repo");
+    fixture(&dir)?;
+
+    let dir = dir.to_string_lossy();
+
+    assert_eq!(
+        utils::run_cmd(&["--hidden", &dir]),
+        indoc!(
+            "4 B ┌─ keep.log
+             1 B ├─ b.txt
+            16 B ├─ .gitignore
+            21 B │  ┌─ HEAD
+            21 B ├─ .git
+            42 B repo
+
+            1 directory, 4 files"
+        ),
+        "Negated path in .gitignore should be shown while others are ignored"
+    );
+
+    assert_eq!(
+        utils::run_cmd(&["--hidden", "--no-git", &dir]),
+        indoc!(
+            "4 B ┌─ keep.log
+             1 B ├─ b.txt
+            16 B ├─ .gitignore
+            21 B repo
+
+            3 files"
+        ),
+        "'--no-git' should hide the git directory without affecting .gitignore negations"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+mod utils;
+
+#[cfg(unix)]
+mod test {
+    use super::utils;
+    use std::{error::Error, fs, os::unix::fs::MetadataExt};
+
+    #[test]
+    fn numeric_owner() -> Result<(), Box<dyn Error>> {
+        let tmp = tempfile::TempDir::new()?;
+        let dir = tmp.path().join("owned");
+        let file = dir.join("file.txt");
+
+        fs::create_dir(&dir)?;
+        fs::write(&file, "abc")?;
+
+        let md = fs::metadata(&file)?;
+
+        let out = utils::run_cmd(&[
+            "--long",
+            "--group",
+            "--numeric-owner",
+            &dir.to_string_lossy(),
+        ]);
+
+        let line = out
+            .lines()
+            .find(|line| line.ends_with("file.txt"))
+            .unwrap_or_else(|| panic!("Missing file.txt in output:\n{out}"));
+
+        let tokens = line.split_whitespace().collect::<Vec<_>>();
+
+        let perms_index = tokens
+            .iter()
+            .position(|token| matches!(token.len(), 10 | 11) && token.starts_with('-'))
+            .unwrap_or_else(|| panic!("Missing permissions in line: {line}"));
+
+        assert_eq!(tokens[perms_index + 1], md.uid().to_string());
+        assert_eq!(tokens[perms_index + 2], md.gid().to_string());
+
+        Ok(())
+    }
+}
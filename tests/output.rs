@@ -0,0 +1,61 @@
+use indoc::indoc;
+use std::{error::Error, fs};
+
+mod utils;
+
+#[test]
+fn output() -> Result<(), Box<dyn Error>> {
+    let tmp = tempfile::TempDir::new()?;
+    let path = tmp.path().join("tree.txt");
+
+    let stdout = utils::run_cmd(&["--output", &path.to_string_lossy(), "tests/data"]);
+
+    assert!(stdout.is_empty(), "Nothing should be printed to stdout");
+
+    let contents = fs::read_to_string(&path)?;
+
+    assert_eq!(
+        contents,
+        indoc!(
+            " 143 B    ┌─ cassildas_song.md
+              143 B ┌─ the_yellow_king
+              100 B ├─ nylarlathotep.txt
+              161 B ├─ nemesis.txt
+               83 B ├─ necronomicon.txt
+              446 B │  ┌─ lipsum.txt
+              446 B ├─ lipsum
+              308 B │  ┌─ polaris.txt
+              308 B ├─ dream_cycle
+             1241 B data
+
+             3 directories, 6 files
+            "
+        ),
+        "Output written to file is expected to be uncolored"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn output_force_color() -> Result<(), Box<dyn Error>> {
+    let tmp = tempfile::TempDir::new()?;
+    let path = tmp.path().join("tree.txt");
+
+    utils::run_cmd(&[
+        "--output",
+        &path.to_string_lossy(),
+        "--color",
+        "force",
+        "tests/data",
+    ]);
+
+    let contents = fs::read(&path)?;
+
+    assert!(
+        contents.contains(&0x1b),
+        "Expected '--color force' to colorize output written to file"
+    );
+
+    Ok(())
+}
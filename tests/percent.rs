@@ -0,0 +1,51 @@
+use indoc::indoc;
+
+mod utils;
+
+#[test]
+fn percent() {
+    assert_eq!(
+        utils::run_cmd(&["--percent", "tests/data"]),
+        indoc!(
+            "143 B 100.0%    ┌─ cassildas_song.md
+             143 B  11.5% ┌─ the_yellow_king
+             100 B   8.1% ├─ nylarlathotep.txt
+             161 B  13.0% ├─ nemesis.txt
+              83 B   6.7% ├─ necronomicon.txt
+             446 B 100.0% │  ┌─ lipsum.txt
+             446 B  35.9% ├─ lipsum
+             308 B 100.0% │  ┌─ polaris.txt
+             308 B  24.8% ├─ dream_cycle
+            1241 B 100.0% data
+
+            3 directories, 6 files"
+        ),
+        "Failed to show percentages of parent directories"
+    )
+}
+
+#[test]
+fn percent_flat() {
+    assert_eq!(
+        utils::run_cmd(&[
+            "--percent",
+            "--layout",
+            "flat",
+            "--level",
+            "1",
+            "tests/data"
+        ]),
+        indoc!(
+            "143 B  11.5%   the_yellow_king
+             100 B   8.1%   nylarlathotep.txt
+             161 B  13.0%   nemesis.txt
+              83 B   6.7%   necronomicon.txt
+             446 B  35.9%   lipsum
+             308 B  24.8%   dream_cycle
+            1241 B 100.0%   data
+
+            3 directories, 6 files"
+        ),
+        "Failed to show percentages in flat layout"
+    )
+}
@@ -0,0 +1,49 @@
+use std::{fs, path::Path};
+
+mod utils;
+
+/// NUL bytes would be lost to the stripping of ANSI escapes done by [`utils::run_cmd`].
+fn run_print0(args: &[&str]) -> String {
+    let output = utils::run_cmd_raw(args, &[]);
+    assert!(output.status.success(), "{}", output.stderr);
+    output.stdout
+}
+
+#[test]
+fn print0() {
+    let root = fs::canonicalize("tests/data").unwrap();
+    let path = |rel: &str| root.join(Path::new(rel)).display().to_string();
+
+    let expected = [
+        "dream_cycle/polaris.txt",
+        "lipsum/lipsum.txt",
+        "necronomicon.txt",
+        "nemesis.txt",
+        "nylarlathotep.txt",
+        "the_yellow_king/cassildas_song.md",
+    ]
+    .map(|rel| format!("{}\0", path(rel)))
+    .concat();
+
+    assert_eq!(
+        run_print0(&["--print0", "tests/data"]),
+        expected,
+        "Expected every file path terminated by a NUL byte"
+    )
+}
+
+#[test]
+fn print0_dirs_only() {
+    let root = fs::canonicalize("tests/data").unwrap();
+    let path = |rel: &str| root.join(Path::new(rel)).display().to_string();
+
+    let expected = ["dream_cycle", "lipsum", "the_yellow_king"]
+        .map(|rel| format!("{}\0", path(rel)))
+        .concat();
+
+    assert_eq!(
+        run_print0(&["--print0", "--dirs-only", "tests/data"]),
+        format!("{}\0{expected}", root.display()),
+        "Expected only directory paths with '--dirs-only'"
+    )
+}
@@ -1,4 +1,5 @@
 use indoc::indoc;
+use std::fs;
 
 mod utils;
 
@@ -67,3 +68,105 @@ fn regex_empty_set_file() {
 fn invalid_regex() {
     utils::run_cmd(&["--pattern", "*.txt", "tests/data"]);
 }
+
+#[cfg(unix)]
+#[test]
+fn regex_non_utf8_file_name() -> Result<(), Box<dyn std::error::Error>> {
+    use std::{ffi::OsStr, fs, os::unix::ffi::OsStrExt};
+
+    let tmp = tempfile::TempDir::new()?;
+    let dir = tmp.path().join("names");
+    fs::create_dir(&dir)?;
+    fs::write(dir.join(OsStr::from_bytes(b"caf\xe9.txt")), "hello")?;
+    fs::write(dir.join("cafe.txt"), "hello")?;
+
+    let dir = dir.to_string_lossy();
+
+    assert_eq!(
+        utils::run_cmd(&["--pattern", r"(?-u:\xe9)\.txt$", &dir]),
+        indoc!(
+            "5 B ┌─ caf\u{FFFD}.txt
+            5 B names
+
+            1 file"
+        ),
+        "Pattern should match the raw bytes of a non-UTF-8 file name"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn regex_full_path() {
+    assert_eq!(
+        utils::run_cmd(&["--full-path", "--pattern", r"^lipsum/", "tests/data"]),
+        indoc!(
+            "446 B    ┌─ lipsum.txt
+             446 B ┌─ lipsum
+             446 B data
+
+             1 directory, 1 file"
+        ),
+        "Regex should be matched against the path relative to the root"
+    );
+
+    assert_eq!(
+        utils::run_cmd(&[
+            "--full-path",
+            "--pattern",
+            r"^dream_cycle$",
+            "--file-type",
+            "dir",
+            "tests/data"
+        ]),
+        indoc!(
+            "308 B    ┌─ polaris.txt
+             308 B ┌─ dream_cycle
+             308 B data
+
+             1 directory, 1 file"
+        ),
+        "Contents of directories whose path matches should be included"
+    );
+}
+
+#[test]
+fn regex_file_type_dir_own_name() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp = tempfile::TempDir::new()?;
+    // A component of the root path matching mustn't pull in everything beneath the root.
+    let root = tmp.path().join("foo").join("root");
+
+    fs::create_dir_all(root.join("foo").join("bar").join("baz"))?;
+    fs::create_dir_all(root.join("qux").join("foo"))?;
+    fs::create_dir_all(root.join("other"))?;
+    fs::write(root.join("foo").join("a.txt"), "a")?;
+    fs::write(root.join("foo").join("bar").join("baz").join("b.txt"), "b")?;
+    fs::write(root.join("qux").join("foo").join("c.txt"), "c")?;
+    fs::write(root.join("other").join("d.txt"), "d")?;
+
+    assert_eq!(
+        utils::run_cmd(&[
+            "--pattern",
+            "^foo$",
+            "--file-type",
+            "dir",
+            &root.to_string_lossy()
+        ]),
+        indoc!(
+            "1 B       ┌─ c.txt
+             1 B    ┌─ foo
+             1 B ┌─ qux
+             1 B │        ┌─ b.txt
+             1 B │     ┌─ baz
+             1 B │  ┌─ bar
+             1 B │  ├─ a.txt
+             2 B ├─ foo
+             3 B root
+
+             5 directories, 3 files"
+        ),
+        "Only directories matching on their own name should be included along with their contents"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use indoc::indoc;
+
+mod utils;
+
+#[test]
+fn scale_zero() {
+    assert_eq!(
+        utils::run_cmd(&[
+            "--layout",
+            "flat",
+            "--human",
+            "--scale",
+            "0",
+            "--level",
+            "1",
+            "tests/data"
+        ]),
+        indoc!(
+            "143   B   the_yellow_king
+            100   B   nylarlathotep.txt
+            161   B   nemesis.txt
+             83   B   necronomicon.txt
+            446   B   lipsum
+            308   B   dream_cycle
+              1 KiB   data
+
+            3 directories, 6 files"
+        ),
+        "Expected integer-only sizes with '--scale 0'"
+    )
+}
+
+#[test]
+fn scale_fixed_unit() {
+    assert_eq!(
+        utils::run_cmd(&[
+            "--layout",
+            "flat",
+            "--fixed-unit",
+            "KiB",
+            "--scale",
+            "3",
+            "--level",
+            "1",
+            "tests/data"
+        ]),
+        indoc!(
+            "0.140 KiB   the_yellow_king
+            0.098 KiB   nylarlathotep.txt
+            0.157 KiB   nemesis.txt
+            0.081 KiB   necronomicon.txt
+            0.436 KiB   lipsum
+            0.301 KiB   dream_cycle
+            1.212 KiB   data
+
+            3 directories, 6 files"
+        ),
+        "Expected three decimal places with '--scale 3'"
+    )
+}
@@ -0,0 +1,24 @@
+mod utils;
+
+#[test]
+fn si() {
+    assert_eq!(
+        utils::run_cmd(&["--si", "tests/data"]),
+        utils::run_cmd(&["--human", "--unit", "si", "tests/data"]),
+        "Expected '--si' to be the same as '--human --unit si'"
+    );
+
+    assert!(
+        utils::run_cmd(&["--si", "tests/data"]).contains("1.2 KB"),
+        "Expected the root to be reported in SI units"
+    )
+}
+
+#[test]
+fn si_explicit_unit() {
+    assert_eq!(
+        utils::run_cmd(&["--si", "--unit", "bin", "tests/data"]),
+        utils::run_cmd(&["--human", "tests/data"]),
+        "Expected an explicit '--unit' to take precedence over '--si'"
+    )
+}
@@ -0,0 +1,62 @@
+mod utils;
+
+/// ANSI escape sequence of the truecolor green at the lower end of the gradient.
+const GREEN: &str = "\u{1b}[38;2;0;255;0m";
+
+/// ANSI escape sequence of the truecolor red at the upper end of the gradient.
+const RED: &str = "\u{1b}[38;2;255;0;0m";
+
+fn run_with_colorterm(colorterm: &str) -> String {
+    let output = utils::run_cmd_raw(
+        &[
+            "--color",
+            "force",
+            "--layout",
+            "flat",
+            "--size-gradient",
+            "83..1241",
+            "tests/data",
+        ],
+        &[("LS_COLORS", ""), ("COLORTERM", colorterm)],
+    );
+
+    assert!(output.status.success());
+
+    output.stdout
+}
+
+#[test]
+fn size_gradient() -> Result<(), Box<dyn std::error::Error>> {
+    let stdout = run_with_colorterm("truecolor");
+
+    let line_for = |name: &str| {
+        stdout
+            .lines()
+            .find(|line| line.contains(name))
+            .unwrap_or_else(|| panic!("Expected '{name}' in output"))
+    };
+
+    assert!(
+        line_for("necronomicon.txt").contains(GREEN),
+        "Expected the smallest size to be at the green end of the gradient"
+    );
+
+    assert!(
+        line_for("data").contains(RED),
+        "Expected the largest size to be at the red end of the gradient"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn size_gradient_no_truecolor() -> Result<(), Box<dyn std::error::Error>> {
+    let stdout = run_with_colorterm("");
+
+    assert!(
+        !stdout.contains(GREEN) && !stdout.contains(RED),
+        "Expected the disk usage theme to be used without truecolor support"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+mod utils;
+
+#[cfg(unix)]
+mod test {
+    use super::utils;
+    use std::{
+        fs::{self, Permissions},
+        os::unix::fs::PermissionsExt,
+    };
+    use tempfile::TempDir;
+
+    #[test]
+    fn skipped() -> Result<(), Box<dyn std::error::Error>> {
+        let tmp = TempDir::new()?;
+        let dir = tmp.path().join("proj");
+        let forbidden = dir.join("forbidden");
+
+        fs::create_dir_all(&forbidden)?;
+        fs::write(dir.join("a.txt"), "abc")?;
+
+        let forbidden = forbidden.canonicalize()?;
+
+        fs::set_permissions(&forbidden, Permissions::from_mode(0o000))?;
+
+        // Privileged users are able to read the directory regardless of its permissions.
+        if fs::read_dir(&forbidden).is_ok() {
+            fs::set_permissions(&forbidden, Permissions::from_mode(0o755))?;
+            return Ok(());
+        }
+
+        let dir = dir.to_string_lossy();
+        let output = utils::run_cmd_raw(&[&dir], &[]);
+        let quiet_output = utils::run_cmd_raw(&["--quiet", &dir], &[]);
+
+        fs::set_permissions(&forbidden, Permissions::from_mode(0o755))?;
+
+        assert!(
+            output.status.success(),
+            "An unreadable directory shouldn't abort the run"
+        );
+
+        let stderr = output.stderr;
+
+        assert!(stderr.contains("1 path skipped (permission denied)"));
+        assert!(stderr.contains(&format!("  {}", forbidden.display())));
+
+        assert!(
+            output.stdout.contains("a.txt"),
+            "Readable entries should still be printed"
+        );
+
+        assert!(quiet_output.status.success());
+
+        assert!(
+            !quiet_output.stderr.contains("skipped"),
+            "'--quiet' should suppress the report of skipped entries"
+        );
+
+        Ok(())
+    }
+}
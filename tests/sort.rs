@@ -84,3 +84,175 @@ fn sort_size() {
         "Failed to sort by descending size"
     )
 }
+
+#[test]
+fn sort_then() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp = tempfile::TempDir::new()?;
+    let dir = tmp.path().join("ties");
+
+    std::fs::create_dir(&dir)?;
+    std::fs::write(dir.join("alpha.txt"), "0123456789")?;
+    std::fs::write(dir.join("beta.txt"), "0123456789")?;
+    std::fs::write(dir.join("gamma.txt"), "01234567890123456789")?;
+
+    let dir = dir.to_string_lossy();
+
+    assert_eq!(
+        utils::run_cmd(&["--sort", "size", "--sort-then", "name", &dir]),
+        indoc!(
+            "10 B ┌─ beta.txt
+            10 B ├─ alpha.txt
+            20 B ├─ gamma.txt
+            40 B ties
+
+            3 files"
+        ),
+        "Failed to order entries of equal size by name"
+    );
+
+    assert_eq!(
+        utils::run_cmd(&["--sort", "size", "--sort-then", "rname", &dir]),
+        indoc!(
+            "10 B ┌─ alpha.txt
+            10 B ├─ beta.txt
+            20 B ├─ gamma.txt
+            40 B ties
+
+            3 files"
+        ),
+        "Failed to order entries of equal size by reversed name"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn sort_reverse() {
+    assert_eq!(
+        utils::run_cmd(&["--sort", "name", "--reverse", "tests/data"]),
+        utils::run_cmd(&["--sort", "rname", "tests/data"]),
+        "Reversing name should be equivalent to rname"
+    );
+
+    assert_eq!(
+        utils::run_cmd(&["--sort", "rsize", "--reverse", "tests/data"]),
+        utils::run_cmd(&["--sort", "size", "tests/data"]),
+        "Reversing rsize should be equivalent to size"
+    );
+}
+
+#[test]
+fn sort_reverse_dir_order() {
+    assert_eq!(
+        utils::run_cmd(&[
+            "--sort",
+            "name",
+            "--reverse",
+            "--dir-order",
+            "first",
+            "tests/data"
+        ]),
+        indoc!(
+            "308 B    ┌─ polaris.txt
+ 308 B ┌─ dream_cycle
+ 446 B │  ┌─ lipsum.txt
+ 446 B ├─ lipsum
+ 143 B │  ┌─ cassildas_song.md
+ 143 B ├─ the_yellow_king
+  83 B ├─ necronomicon.txt
+ 161 B ├─ nemesis.txt
+ 100 B ├─ nylarlathotep.txt
+1241 B data
+
+3 directories, 6 files"
+        ),
+        "Directories should remain grouped when reversing"
+    );
+}
+
+#[test]
+fn sort_extension() {
+    assert_eq!(
+        utils::run_cmd(&["--sort", "extension", "tests/data"]),
+        indoc!(
+            "100 B ┌─ nylarlathotep.txt
+ 161 B ├─ nemesis.txt
+  83 B ├─ necronomicon.txt
+ 143 B │  ┌─ cassildas_song.md
+ 143 B ├─ the_yellow_king
+ 446 B │  ┌─ lipsum.txt
+ 446 B ├─ lipsum
+ 308 B │  ┌─ polaris.txt
+ 308 B ├─ dream_cycle
+1241 B data
+
+3 directories, 6 files"
+        ),
+        "Failed to group entries by extension"
+    );
+}
+
+#[test]
+fn sort_extension_case_insensitive() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp = tempfile::TempDir::new()?;
+    let root = tmp.path().join("exts");
+    std::fs::create_dir(&root)?;
+
+    for name in ["b.RS", "a.toml", "c.rs", "README"] {
+        std::fs::write(root.join(name), "")?;
+    }
+
+    assert_eq!(
+        utils::run_cmd(&[
+            "--sort",
+            "extension",
+            "--suppress-size",
+            &root.to_string_lossy()
+        ]),
+        indoc!(
+            "┌─ a.toml
+             ├─ c.rs
+             ├─ b.RS
+             ├─ README
+             exts
+
+            4 files"
+        ),
+        "Extensions should be compared case-insensitively with files lacking one ordered first"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn sort_size_ties_by_name() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp = tempfile::TempDir::new()?;
+    let root = tmp.path().join("ties");
+    std::fs::create_dir(&root)?;
+
+    for name in ["c", "a", "d", "b"] {
+        std::fs::write(root.join(name), "x")?;
+    }
+
+    let root = root.to_string_lossy();
+
+    for threads in ["1", "4"] {
+        for sort in ["size", "rsize"] {
+            assert_eq!(
+                utils::run_cmd(&["--sort", sort, "--threads", threads, &root]),
+                indoc!(
+                    "1 B ┌─ d
+                     1 B ├─ c
+                     1 B ├─ b
+                     1 B ├─ a
+                     4 B ties
+
+                     4 files"
+                ),
+                "Entries of equal size should be ordered by name"
+            );
+        }
+    }
+
+    Ok(())
+}
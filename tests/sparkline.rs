@@ -0,0 +1,35 @@
+use indoc::indoc;
+
+mod utils;
+
+#[test]
+fn sparkline() {
+    assert_eq!(
+        utils::run_cmd(&["--sparkline", "tests/data"]),
+        indoc!(
+            "143 B    ┌─ cassildas_song.md
+             143 B ┌─ the_yellow_king █
+             100 B ├─ nylarlathotep.txt
+             161 B ├─ nemesis.txt
+              83 B ├─ necronomicon.txt
+             446 B │  ┌─ lipsum.txt
+             446 B ├─ lipsum █
+             308 B │  ┌─ polaris.txt
+             308 B ├─ dream_cycle █
+            1241 B data ▆█▂▄▃▃
+
+            3 directories, 6 files"
+        ),
+        "Expected one bar per child scaled relative to the largest child"
+    )
+}
+
+#[test]
+fn sparkline_ascii() {
+    let out = utils::run_cmd(&["--sparkline", "--ascii", "tests/data"]);
+
+    assert!(
+        out.lines().any(|line| line == "1241 B data 571322"),
+        "Expected sparkline levels to be drawn as digits:\n{out}"
+    );
+}
@@ -0,0 +1,27 @@
+mod utils;
+
+#[test]
+fn stats() {
+    let output = utils::run_cmd_raw(&["--stats", "tests/data"], &[]);
+
+    assert!(output.status.success());
+
+    let stderr = output.stderr;
+
+    for line in ["entries: 10", "skipped: 0", "bytes: 1241"] {
+        assert!(
+            stderr.lines().any(|l| l == line),
+            "Missing '{line}' in:\n{stderr}"
+        );
+    }
+
+    assert!(
+        stderr.lines().any(|line| line.starts_with("elapsed: ")),
+        "Missing elapsed time in:\n{stderr}"
+    );
+
+    assert!(
+        !output.stdout.contains("entries: "),
+        "Stats should only be printed to stderr"
+    );
+}
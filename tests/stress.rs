@@ -0,0 +1,29 @@
+use std::{error::Error, fs};
+
+mod utils;
+
+/// Walks a synthetic tree with more entries than the traversal channel can hold at once to ensure
+/// that traversal threads waiting on a full channel neither deadlock nor drop entries.
+#[test]
+fn stress() -> Result<(), Box<dyn Error>> {
+    let tmp = tempfile::TempDir::new()?;
+    let root = tmp.path().join("stress");
+
+    for i in 0..10 {
+        let dir = root.join(format!("dir_{i}"));
+        fs::create_dir_all(&dir)?;
+
+        for j in 0..10_000 {
+            fs::File::create(dir.join(format!("file_{j}")))?;
+        }
+    }
+
+    let out = utils::run_cmd(&["--level", "1", "--total", &root.to_string_lossy()]);
+
+    assert!(
+        out.ends_with("10 directories, 100000 files, 0 B"),
+        "Expected every entry to be accounted for:\n{out}"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use indoc::indoc;
+use std::{error::Error, fs, path::Path};
+
+mod utils;
+
+fn fixture(home: &Path) -> Result<(), Box<dyn Error>> {
+    fs::create_dir_all(home.join("alice"))?;
+    fs::write(home.join("alice").join("notes.txt"), "hello")?;
+    fs::write(home.join("alice").join("todo.txt"), "abc")?;
+    fs::write(home.join("shared.txt"), "hi")?;
+    Ok(())
+}
+
+#[test]
+fn strip_prefix() -> Result<(), Box<dyn Error>> {
+    let tmp = tempfile::TempDir::new()?;
+    let home = tmp.path().join("home");
+    fixture(&home)?;
+
+    let home = home.to_string_lossy();
+
+    assert_eq!(
+        utils::run_cmd(&["--layout", "flat", "--strip-prefix", "alice/", &home]),
+        indoc!(
+            "2 B   shared.txt
+             3 B   todo.txt
+             5 B   notes.txt
+             8 B   alice
+            10 B   home
+
+            1 directory, 3 files"
+        ),
+        "Failed to strip home directory prefix from displayed paths"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn strip_prefix_csv() -> Result<(), Box<dyn Error>> {
+    let tmp = tempfile::TempDir::new()?;
+    let home = tmp.path().join("home");
+    fixture(&home)?;
+
+    let home = home.to_string_lossy();
+
+    assert_eq!(
+        utils::run_cmd(&["--csv", "--strip-prefix", "alice/", &home]),
+        indoc!(
+            "path,size_bytes,file_type,depth
+            home,10,directory,0
+            alice,8,directory,1
+            notes.txt,5,file,2
+            todo.txt,3,file,2
+            shared.txt,2,file,1"
+        ),
+        "Failed to strip home directory prefix from CSV paths"
+    );
+
+    Ok(())
+}
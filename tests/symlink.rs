@@ -36,4 +36,143 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn symlinked_root() -> Result<(), Box<dyn std::error::Error>> {
+        let tmp = TempDir::new()?;
+        let real = tmp.path().join("real");
+        let link = tmp.path().join("root_link");
+
+        std::fs::create_dir(&real)?;
+        std::fs::write(real.join("a.txt"), "abc")?;
+        symlink("a.txt", real.join("inner"))?;
+        symlink(&real, &link)?;
+
+        let link = link.to_string_lossy();
+
+        assert_eq!(
+            super::utils::run_cmd(&[&link]),
+            indoc!(
+                "5 B ┌─ inner -> a.txt
+                3 B ├─ a.txt
+                8 B real
+
+                1 file, 1 link"
+            ),
+            "Root symlink should be followed but not symlinks within it"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn symlink_cycle() -> Result<(), Box<dyn std::error::Error>> {
+        let tmp = TempDir::new()?;
+        let root = tmp.path().join("root");
+        let nested = root.join("nested");
+
+        std::fs::create_dir_all(&nested)?;
+        std::fs::write(nested.join("a.txt"), "abc")?;
+        symlink("..", nested.join("up"))?;
+
+        let out = super::utils::run_cmd(&["--follow", &root.to_string_lossy()]);
+
+        assert!(
+            out.lines()
+                .any(|line| line.ends_with("─ up -> .. (symlink cycle)")),
+            "Expected symlink pointing to its ancestor to be marked:\n{out}"
+        );
+
+        assert_eq!(
+            out.matches("a.txt").count(),
+            1,
+            "Symlink cycle should not be descended into:\n{out}"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn broken_link() -> Result<(), Box<dyn std::error::Error>> {
+        let tmp = TempDir::new()?;
+        let root = tmp.path().join("root");
+
+        std::fs::create_dir(&root)?;
+        std::fs::write(root.join("a.txt"), "abc")?;
+        symlink("missing.txt", root.join("dangling"))?;
+
+        let root = root.to_string_lossy();
+
+        let output = super::utils::run_cmd_raw(&["--color", "force", &root], &[]);
+
+        assert!(output.status.success());
+
+        assert!(
+            output.stdout.contains("\x1b[9;31mmissing.txt"),
+            "Expected target of broken symlink to be painted red with a strikethrough"
+        );
+
+        assert_eq!(
+            super::utils::run_cmd(&["--no-broken", &root]),
+            indoc!(
+                "3 B ┌─ a.txt
+                3 B root
+
+                1 file"
+            ),
+            "Broken symlinks should be hidden with '--no-broken'"
+        );
+
+        Ok(())
+    }
+    #[test]
+    fn follow_depth() -> Result<(), Box<dyn std::error::Error>> {
+        let tmp = TempDir::new()?;
+        let root = tmp.path().join("root");
+        let outer = tmp.path().join("outer");
+        let inner = tmp.path().join("inner");
+
+        std::fs::create_dir(&root)?;
+        std::fs::create_dir(&outer)?;
+        std::fs::create_dir(&inner)?;
+        std::fs::write(outer.join("outer.txt"), "abc")?;
+        std::fs::write(inner.join("inner.txt"), "abc")?;
+        symlink(&inner, outer.join("inner_link"))?;
+        symlink(&outer, root.join("outer_link"))?;
+
+        let root = root.to_string_lossy();
+
+        let out = |follow_depth: Option<&str>| {
+            let mut args = vec!["--follow", "--suppress-size"];
+
+            if let Some(depth) = follow_depth {
+                args.extend(["--follow-depth", depth]);
+            }
+
+            args.push(&root);
+            super::utils::run_cmd(&args)
+        };
+
+        let no_descent = out(Some("0"));
+        assert!(no_descent.contains("outer_link"));
+        assert!(
+            !no_descent.contains("outer.txt"),
+            "Symlinked directories shouldn't be descended into with '--follow-depth 0'"
+        );
+
+        let one_hop = out(Some("1"));
+        assert!(one_hop.contains("outer.txt"));
+        assert!(one_hop.contains("inner_link"));
+        assert!(
+            !one_hop.contains("inner.txt"),
+            "Only one symlink should be descended through with '--follow-depth 1'"
+        );
+
+        assert!(
+            out(None).contains("inner.txt"),
+            "Every symlink should be descended through without '--follow-depth'"
+        );
+
+        Ok(())
+    }
 }
@@ -0,0 +1,30 @@
+use indoc::indoc;
+use std::{error::Error, fs};
+use tempfile::TempDir;
+
+mod utils;
+
+#[test]
+fn text_only() -> Result<(), Box<dyn Error>> {
+    let tmp = TempDir::new()?;
+    let dir = tmp.path().join("text_only");
+
+    fs::create_dir(&dir)?;
+    fs::write(dir.join("notes.txt"), "Hello, world!")?;
+    fs::write(dir.join("blob.bin"), b"\x7fELF\x00\x00\x01\x02")?;
+
+    let out = utils::run_cmd(&["--text-only", &dir.to_string_lossy()]);
+
+    assert_eq!(
+        out,
+        indoc!(
+            "13 B ┌─ notes.txt
+            13 B text_only
+
+            1 file"
+        ),
+        "Failed to omit binary file"
+    );
+
+    Ok(())
+}
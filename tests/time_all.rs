@@ -0,0 +1,46 @@
+mod utils;
+
+#[cfg(unix)]
+mod test {
+    use super::utils;
+    use std::{error::Error, fs, process::Command};
+
+    #[test]
+    fn time_all() -> Result<(), Box<dyn Error>> {
+        let tmp = tempfile::TempDir::new()?;
+        let dir = tmp.path().join("stamps");
+        let file = dir.join("file.txt");
+
+        fs::create_dir(&dir)?;
+        fs::write(&file, "abc")?;
+
+        for (flag, stamp) in [("-m", "200102031200"), ("-a", "200203041200")] {
+            let status = Command::new("touch")
+                .args([flag, "-t", stamp])
+                .arg(&file)
+                .status()?;
+
+            assert!(status.success(), "Failed to set timestamps of fixture");
+        }
+
+        let out = utils::run_cmd(&[
+            "--long",
+            "--time-all",
+            "--time-format",
+            "short",
+            &dir.to_string_lossy(),
+        ]);
+
+        let line = out
+            .lines()
+            .find(|line| line.ends_with("file.txt"))
+            .expect("Missing file.txt in output");
+
+        assert!(
+            line.contains("] [  2001-02-03] [  2002-03-04]"),
+            "Expected modified and accessed timestamps after created timestamp:\n{out}"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+mod utils;
+
+#[cfg(unix)]
+mod test {
+    use super::utils;
+    use std::{error::Error, fs, process::Command};
+
+    #[test]
+    fn time_custom() -> Result<(), Box<dyn Error>> {
+        let tmp = tempfile::TempDir::new()?;
+        let dir = tmp.path().join("stamps");
+        let file = dir.join("file.txt");
+
+        fs::create_dir(&dir)?;
+        fs::write(&file, "abc")?;
+
+        let status = Command::new("touch")
+            .args(["-m", "-t", "200102031200"])
+            .arg(&file)
+            .status()?;
+
+        assert!(status.success(), "Failed to set timestamps of fixture");
+
+        let out = utils::run_cmd(&["--time-custom", "%Y/%m/%d", &dir.to_string_lossy()]);
+
+        let line = out
+            .lines()
+            .find(|line| line.ends_with("file.txt"))
+            .expect("Missing file.txt in output");
+
+        assert!(
+            line.contains("[  2001/02/03]"),
+            "Expected '--time-custom' to imply '--long' and format the timestamp:\n{out}"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn time_custom_invalid() {
+        let output = utils::run_cmd_raw(&["--time-custom", "%Y-%Q", "tests/data"], &[]);
+
+        assert!(!output.status.success());
+
+        assert!(
+            output
+                .stderr
+                .contains("'%Y-%Q' is not a valid strftime format string"),
+            "Invalid format should be reported before rendering:\n{}",
+            output.stderr
+        );
+    }
+}
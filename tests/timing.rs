@@ -0,0 +1,22 @@
+mod utils;
+
+#[test]
+fn timing() {
+    let output = utils::run_cmd_raw(&["--timing", "tests/data"], &[]);
+
+    assert!(output.status.success());
+
+    let stderr = output.stderr;
+
+    for label in ["traversal: ", "assembly: ", "rendering: "] {
+        assert!(
+            stderr.lines().any(|line| line.starts_with(label)),
+            "Missing '{label}' duration in:\n{stderr}"
+        );
+    }
+
+    assert!(
+        !output.stdout.contains("traversal: "),
+        "Timing should only be printed to stderr"
+    );
+}
@@ -0,0 +1,46 @@
+use std::{fs, path::Path};
+
+mod utils;
+
+#[test]
+fn top() {
+    let root = fs::canonicalize("tests/data").unwrap();
+    let path = |rel: &str| root.join(Path::new(rel)).display().to_string();
+
+    assert_eq!(
+        utils::run_cmd(&["--top", "3", "tests/data"]),
+        [
+            format!("446 B {}", path("lipsum/lipsum.txt")),
+            format!(" 308 B {}", path("dream_cycle/polaris.txt")),
+            format!(" 161 B {}", path("nemesis.txt")),
+        ]
+        .join("\n"),
+        "Expected the three largest files, largest first"
+    )
+}
+
+#[test]
+fn top_human() {
+    let root = fs::canonicalize("tests/data").unwrap();
+
+    assert_eq!(
+        utils::run_cmd(&["--top", "1", "--human", "tests/data"]),
+        format!(
+            "446   B {}",
+            root.join("lipsum").join("lipsum.txt").display()
+        ),
+        "Sizes should be aligned in the same manner as the tree"
+    )
+}
+
+#[test]
+fn top_strip_prefix() {
+    let root = fs::canonicalize("tests/data").unwrap();
+    let prefix = format!("{}{}", root.display(), std::path::MAIN_SEPARATOR);
+
+    assert_eq!(
+        utils::run_cmd(&["--top", "1", "--strip-prefix", &prefix, "tests/data"]),
+        format!("446 B {}", Path::new("lipsum").join("lipsum.txt").display()),
+        "Paths should have the prefix given by '--strip-prefix' removed"
+    )
+}
@@ -0,0 +1,41 @@
+mod utils;
+
+fn footer(args: &[&str]) -> String {
+    utils::run_cmd(args).lines().last().unwrap().to_owned()
+}
+
+#[test]
+fn total() {
+    assert_eq!(
+        footer(&["--total", "tests/data"]),
+        "3 directories, 6 files, 1241 B"
+    );
+
+    assert_eq!(
+        footer(&["--total", "--layout", "flat", "tests/data"]),
+        "3 directories, 6 files, 1241 B"
+    );
+}
+
+#[test]
+fn total_human() {
+    assert_eq!(
+        footer(&["--total", "--human", "tests/data"]),
+        "3 directories, 6 files, 1.2 KiB"
+    );
+}
+
+#[test]
+fn total_pruned() {
+    assert_eq!(
+        footer(&["--total", "--glob", "--pattern", "*.txt", "tests/data"]),
+        "2 directories, 5 files, 1098 B",
+        "Pruned directories shouldn't count towards the total"
+    );
+
+    assert_eq!(
+        footer(&["--total", "--dirs-only", "tests/data"]),
+        "3 directories, 1241 B",
+        "Files shouldn't count towards the total when only directories are shown"
+    );
+}
@@ -0,0 +1,20 @@
+mod utils;
+
+fn raw_output(args: &[&str]) -> String {
+    let output = utils::run_cmd_raw(args, &[]);
+
+    assert!(output.status.success());
+
+    output.stdout
+}
+
+#[test]
+fn trailing_newline() {
+    let with_newline = raw_output(&["tests/data"]);
+    assert!(with_newline.ends_with("files\n"));
+
+    let without_newline = raw_output(&["--no-trailing-newline", "tests/data"]);
+    assert!(without_newline.ends_with("files"));
+
+    assert_eq!(with_newline.trim_end_matches('\n'), without_newline);
+}
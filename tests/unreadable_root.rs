@@ -0,0 +1,44 @@
+mod utils;
+
+#[cfg(unix)]
+mod test {
+    use super::utils;
+    use std::{
+        fs::{self, Permissions},
+        os::unix::fs::PermissionsExt,
+    };
+    use tempfile::TempDir;
+
+    #[test]
+    fn unreadable_root() -> Result<(), Box<dyn std::error::Error>> {
+        let tmp = TempDir::new()?;
+        let dir = tmp.path().join("forbidden");
+
+        fs::create_dir(&dir)?;
+
+        let dir = dir.canonicalize()?;
+
+        fs::set_permissions(&dir, Permissions::from_mode(0o000))?;
+
+        // Privileged users are able to read the directory regardless of its permissions.
+        if fs::read_dir(&dir).is_ok() {
+            fs::set_permissions(&dir, Permissions::from_mode(0o755))?;
+            return Ok(());
+        }
+
+        let output = utils::run_cmd_raw(&[&dir.to_string_lossy()], &[]);
+
+        fs::set_permissions(&dir, Permissions::from_mode(0o755))?;
+
+        assert!(!output.status.success());
+
+        let expected = format!("Cannot read root directory '{}'", dir.display());
+
+        assert!(
+            output.stderr.contains(&expected),
+            "Expected an explicit error for an unreadable root directory"
+        );
+
+        Ok(())
+    }
+}
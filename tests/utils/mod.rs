@@ -1,11 +1,29 @@
+// Not every test crate that includes this module uses every helper.
+#![allow(dead_code)]
+
 use std::process::Command;
+use std::process::ExitStatus;
 use std::process::Stdio;
 use strip_ansi_escapes::strip as strip_ansi_escapes;
 
-pub fn run_cmd(args: &[&str]) -> String {
+/// Exit status along with the unprocessed output of an invocation of `erd`.
+pub struct RawOutput {
+    pub status: ExitStatus,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+/// Prepares an invocation of `erd` with the arguments that every test relies on for deterministic
+/// output followed by `args`.
+fn erd(args: &[&str]) -> Command {
     let mut cmd = Command::new("cargo");
+    cmd.arg("run");
+
+    // Run the binary that was built with the same optional features as the tests.
+    #[cfg(feature = "zip")]
+    cmd.args(["--features", "zip"]);
+
     cmd.args([
-        "run",
         "--",
         "--threads",
         "1",
@@ -20,14 +38,15 @@ pub fn run_cmd(args: &[&str]) -> String {
         cmd.arg(arg);
     }
 
-    let output = cmd
-        .stdin(Stdio::null())
+    cmd.stdin(Stdio::null())
         .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .spawn()
-        .unwrap()
-        .wait_with_output()
-        .unwrap();
+        .stderr(Stdio::piped());
+
+    cmd
+}
+
+pub fn run_cmd(args: &[&str]) -> String {
+    let output = erd(args).spawn().unwrap().wait_with_output().unwrap();
 
     if !output.status.success() {
         let err_msg = String::from_utf8(output.stderr).unwrap();
@@ -39,3 +58,22 @@ pub fn run_cmd(args: &[&str]) -> String {
         .trim()
         .to_string()
 }
+
+/// Runs `erd` with `args` and the environment variables in `envs` without asserting success or
+/// touching the output. Color related environment variables of the test's own environment are
+/// cleared beforehand.
+pub fn run_cmd_raw(args: &[&str], envs: &[(&str, &str)]) -> RawOutput {
+    let output = erd(args)
+        .env_remove("NO_COLOR")
+        .env_remove("CLICOLOR")
+        .env_remove("CLICOLOR_FORCE")
+        .envs(envs.iter().copied())
+        .output()
+        .unwrap();
+
+    RawOutput {
+        status: output.status,
+        stdout: String::from_utf8(output.stdout).unwrap(),
+        stderr: String::from_utf8(output.stderr).unwrap(),
+    }
+}
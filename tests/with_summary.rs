@@ -0,0 +1,56 @@
+use indoc::indoc;
+
+mod utils;
+
+#[test]
+fn with_summary() {
+    assert_eq!(
+        utils::run_cmd(&["--with-summary", "3", "tests/data"]),
+        indoc!(
+            "143 B    ┌─ cassildas_song.md
+             143 B ┌─ the_yellow_king
+             100 B ├─ nylarlathotep.txt
+             161 B ├─ nemesis.txt
+              83 B ├─ necronomicon.txt
+             446 B │  ┌─ lipsum.txt
+             446 B ├─ lipsum
+             308 B │  ┌─ polaris.txt
+             308 B ├─ dream_cycle
+            1241 B data
+
+            3 directories, 6 files
+
+            largest entries:
+            446 B lipsum
+            446 B lipsum/lipsum.txt
+            308 B dream_cycle"
+        ),
+        "Failed to print size-sorted summary beneath the tree"
+    )
+}
+
+#[test]
+fn with_summary_flat() {
+    assert_eq!(
+        utils::run_cmd(&["--with-summary", "2", "--layout", "flat", "tests/data"]),
+        indoc!(
+            "143 B   the_yellow_king/cassildas_song.md
+             143 B   the_yellow_king
+             100 B   nylarlathotep.txt
+             161 B   nemesis.txt
+              83 B   necronomicon.txt
+             446 B   lipsum/lipsum.txt
+             446 B   lipsum
+             308 B   dream_cycle/polaris.txt
+             308 B   dream_cycle
+            1241 B   data
+
+            3 directories, 6 files
+
+            largest entries:
+            446 B lipsum
+            446 B lipsum/lipsum.txt"
+        ),
+        "Failed to print size-sorted summary beneath flat layout"
+    )
+}
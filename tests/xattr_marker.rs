@@ -0,0 +1,120 @@
+mod utils;
+
+#[cfg(unix)]
+mod test {
+    use super::utils;
+    use std::{error::Error, ffi::CString, fs, os::unix::ffi::OsStrExt, path::Path};
+
+    /// Attempts to set a user extended attribute on the file at `path`, returning whether or not
+    /// the underlying filesystem supports it.
+    fn set_xattr(path: &Path) -> Result<bool, Box<dyn Error>> {
+        let path = CString::new(path.as_os_str().as_bytes())?;
+        let name = CString::new("user.erdtree")?;
+        let value = b"1";
+
+        #[cfg(not(target_os = "macos"))]
+        let res = unsafe {
+            libc::setxattr(
+                path.as_ptr(),
+                name.as_ptr(),
+                value.as_ptr().cast(),
+                value.len(),
+                0,
+            )
+        };
+
+        #[cfg(target_os = "macos")]
+        let res = unsafe {
+            libc::setxattr(
+                path.as_ptr(),
+                name.as_ptr(),
+                value.as_ptr().cast(),
+                value.len(),
+                0,
+                0,
+            )
+        };
+
+        Ok(res == 0)
+    }
+
+    /// Whether or not the symbolic permissions of `file_name` in `out` carry the `@` marker.
+    fn has_marker(out: &str, file_name: &str) -> bool {
+        let line = out
+            .lines()
+            .find(|line| line.ends_with(file_name))
+            .unwrap_or_else(|| panic!("Missing {file_name} in output:\n{out}"));
+
+        line.split_whitespace()
+            .any(|token| token.len() == 11 && token.ends_with('@'))
+    }
+
+    /// The extended attribute count shown for `file_name` in `out` which follows the owner.
+    fn xattr_count<'a>(out: &'a str, file_name: &str) -> &'a str {
+        let line = out
+            .lines()
+            .find(|line| line.ends_with(file_name))
+            .unwrap_or_else(|| panic!("Missing {file_name} in output:\n{out}"));
+
+        let tokens = line.split_whitespace().collect::<Vec<_>>();
+
+        let perms_index = tokens
+            .iter()
+            .position(|token| matches!(token.len(), 10 | 11) && token.starts_with('-'))
+            .unwrap_or_else(|| panic!("Missing permissions in line: {line}"));
+
+        tokens[perms_index + 2]
+    }
+
+    #[test]
+    fn xattr_marker() -> Result<(), Box<dyn Error>> {
+        let tmp = tempfile::TempDir::new()?;
+        let dir = tmp.path().join("attrs");
+
+        fs::create_dir(&dir)?;
+        fs::write(dir.join("plain.txt"), "abc")?;
+        fs::write(dir.join("tagged.txt"), "abc")?;
+
+        let supports_xattrs = set_xattr(&dir.join("tagged.txt"))?;
+
+        let dir = dir.to_string_lossy();
+
+        let run = |marker| utils::run_cmd(&["--long", "--xattr-marker", marker, &dir]);
+
+        let auto = run("auto");
+        assert!(!has_marker(&auto, "plain.txt"));
+        assert_eq!(has_marker(&auto, "tagged.txt"), supports_xattrs);
+
+        let always = run("always");
+        assert!(has_marker(&always, "plain.txt"));
+        assert!(has_marker(&always, "tagged.txt"));
+
+        let never = run("never");
+        assert!(!has_marker(&never, "plain.txt"));
+        assert!(!has_marker(&never, "tagged.txt"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn xattr_count_column() -> Result<(), Box<dyn Error>> {
+        let tmp = tempfile::TempDir::new()?;
+        let dir = tmp.path().join("attrs");
+
+        fs::create_dir(&dir)?;
+        fs::write(dir.join("plain.txt"), "abc")?;
+        fs::write(dir.join("tagged.txt"), "abc")?;
+
+        let supports_xattrs = set_xattr(&dir.join("tagged.txt"))?;
+
+        let out = utils::run_cmd(&["--long", "--xattr", &dir.to_string_lossy()]);
+
+        assert_eq!(xattr_count(&out, "plain.txt"), "-");
+
+        if supports_xattrs {
+            assert_eq!(xattr_count(&out, "tagged.txt"), "1");
+        }
+
+        Ok(())
+    }
+}